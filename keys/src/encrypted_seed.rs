@@ -0,0 +1,166 @@
+use crate::error::KeystoreError;
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Version written by [`EncryptedSeed::encrypt`].
+pub const ENCRYPTED_SEED_VERSION: u8 = 1;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+
+/// Key derivation function and the work factors it was run with.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "name", rename_all = "lowercase")]
+pub enum Kdf {
+    Argon2id {
+        /// Memory in KiB.
+        m_cost: u32,
+        t_cost: u32,
+        p_cost: u32,
+    },
+}
+
+impl Default for Kdf {
+    /// OWASP recommended Argon2id parameters.
+    fn default() -> Self {
+        Kdf::Argon2id {
+            m_cost: 19 * 1024,
+            t_cost: 2,
+            p_cost: 1,
+        }
+    }
+}
+
+impl Kdf {
+    fn derive_key(
+        &self,
+        password: &str,
+        salt: &[u8],
+    ) -> Result<Zeroizing<[u8; KEY_LEN]>, KeystoreError> {
+        let mut key = Zeroizing::new([0u8; KEY_LEN]);
+        match self {
+            Kdf::Argon2id {
+                m_cost,
+                t_cost,
+                p_cost,
+            } => {
+                let params =
+                    Params::new(*m_cost, *t_cost, *p_cost, Some(KEY_LEN)).map_err(|e| {
+                        KeystoreError::Corrupted(format!("Invalid KDF parameters: {e}"))
+                    })?;
+                Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+                    .hash_password_into(password.as_bytes(), salt, &mut *key)
+                    .map_err(|e| KeystoreError::Corrupted(format!("Key derivation failed: {e}")))?;
+            }
+        }
+        Ok(key)
+    }
+}
+
+/// Seed phrase encrypted with XChaCha20-Poly1305 under a key derived from
+/// the account password. Salt, nonce and ciphertext are base64 encoded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct EncryptedSeed {
+    pub version: u8,
+    pub kdf: Kdf,
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+impl EncryptedSeed {
+    pub fn encrypt(seed: &str, password: &str) -> Result<Self, KeystoreError> {
+        let kdf = Kdf::default();
+        let salt = random_bytes::<SALT_LEN>()?;
+        let nonce = random_bytes::<NONCE_LEN>()?;
+        let key = kdf.derive_key(password, &salt)?;
+
+        let ciphertext = XChaCha20Poly1305::new(Key::from_slice(&*key))
+            .encrypt(XNonce::from_slice(&nonce), seed.as_bytes())
+            .map_err(|e| KeystoreError::Encryption(e.to_string()))?;
+
+        Ok(Self {
+            version: ENCRYPTED_SEED_VERSION,
+            kdf,
+            salt: STANDARD.encode(salt),
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(ciphertext),
+        })
+    }
+
+    /// A failed authentication tag is reported as a wrong password; anything
+    /// that cannot even be parsed as corruption. The plaintext is wiped when
+    /// dropped.
+    pub fn decrypt(&self, password: &str) -> Result<Zeroizing<String>, KeystoreError> {
+        if self.version != ENCRYPTED_SEED_VERSION {
+            return Err(KeystoreError::UnsupportedVersion(self.version));
+        }
+        let salt = decode_field("salt", &self.salt)?;
+        let nonce = decode_field("nonce", &self.nonce)?;
+        let ciphertext = decode_field("ciphertext", &self.ciphertext)?;
+        if nonce.len() != NONCE_LEN {
+            return Err(KeystoreError::Corrupted(format!(
+                "Nonce must be {NONCE_LEN} bytes"
+            )));
+        }
+
+        let key = self.kdf.derive_key(password, &salt)?;
+        let seed = XChaCha20Poly1305::new(Key::from_slice(&*key))
+            .decrypt(XNonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| KeystoreError::WrongPassword)?;
+        String::from_utf8(seed).map(Zeroizing::new).map_err(|e| {
+            e.into_bytes().zeroize();
+            KeystoreError::Corrupted("Seed is not valid UTF-8".to_string())
+        })
+    }
+}
+
+fn decode_field(name: &str, value: &str) -> Result<Vec<u8>, KeystoreError> {
+    STANDARD
+        .decode(value)
+        .map_err(|e| KeystoreError::Corrupted(format!("Invalid {name}: {e}")))
+}
+
+pub fn random_bytes<const N: usize>() -> Result<[u8; N], KeystoreError> {
+    let mut bytes = [0u8; N];
+    getrandom::getrandom(&mut bytes).map_err(|e| KeystoreError::Encryption(e.to_string()))?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+    #[test]
+    fn decrypts_what_it_encrypted() {
+        let encrypted = EncryptedSeed::encrypt(PHRASE, "correct horse").unwrap();
+        assert_eq!(encrypted.version, ENCRYPTED_SEED_VERSION);
+        assert_eq!(encrypted.decrypt("correct horse").unwrap().as_str(), PHRASE);
+    }
+
+    #[test]
+    fn rejects_a_wrong_password() {
+        let encrypted = EncryptedSeed::encrypt(PHRASE, "correct horse").unwrap();
+        assert_eq!(
+            encrypted.decrypt("battery staple").unwrap_err(),
+            KeystoreError::WrongPassword
+        );
+    }
+
+    #[test]
+    fn rejects_an_unknown_version() {
+        let mut encrypted = EncryptedSeed::encrypt(PHRASE, "correct horse").unwrap();
+        encrypted.version = ENCRYPTED_SEED_VERSION + 1;
+        assert_eq!(
+            encrypted.decrypt("correct horse").unwrap_err(),
+            KeystoreError::UnsupportedVersion(ENCRYPTED_SEED_VERSION + 1)
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum KeystoreError {
+    #[error("Wrong password")]
+    WrongPassword,
+    #[error("Wrong password for {0}")]
+    WrongPasswordFor(String),
+    #[error("Corrupted keystore entry: {0}")]
+    Corrupted(String),
+    #[error("Unsupported keystore version {0}")]
+    UnsupportedVersion(u8),
+    #[error("Encryption failed: {0}")]
+    Encryption(String),
+    #[error("Unsupported keystore format: {0}")]
+    UnsupportedFormat(String),
+    #[error("{0}")]
+    InvalidSecret(String),
+}
@@ -0,0 +1,3 @@
+pub mod encrypted_seed;
+pub mod error;
+pub mod seed;
@@ -0,0 +1,79 @@
+use crate::error::KeystoreError;
+use hmac::Hmac;
+use sha2::Sha512;
+use sp_core_hashing::blake2_256;
+use subxt_core::ext::codec::Encode;
+use subxt_signer::bip39::Mnemonic;
+use subxt_signer::{ExposeSecret, SecretUri};
+use zeroize::Zeroizing;
+
+/// 32 byte seed shared by all schemes: the first half of the substrate-bip39
+/// PBKDF2 output, or the phrase itself when it is `0x` prefixed hex. Every
+/// intermediate buffer is wiped when dropped.
+pub fn mini_secret_seed(
+    phrase: &str,
+    password: Option<&str>,
+) -> Result<Zeroizing<[u8; 32]>, KeystoreError> {
+    let mut seed = Zeroizing::new([0u8; 32]);
+    if let Some(hex_seed) = phrase.strip_prefix("0x") {
+        hex::decode_to_slice(hex_seed, &mut *seed)
+            .map_err(|e| KeystoreError::InvalidSecret(format!("Invalid seed: {e}")))?;
+        return Ok(seed);
+    }
+    let mnemonic = Mnemonic::parse(phrase)
+        .map_err(|e| KeystoreError::InvalidSecret(format!("Invalid account phrase: {e}")))?;
+    let entropy = Zeroizing::new(mnemonic.to_entropy());
+    let salt = Zeroizing::new(format!("mnemonic{}", password.unwrap_or_default()));
+    let mut big_seed = Zeroizing::new([0u8; 64]);
+    pbkdf2::pbkdf2::<Hmac<Sha512>>(&entropy, salt.as_bytes(), 2048, &mut *big_seed)
+        .map_err(|e| KeystoreError::InvalidSecret(e.to_string()))?;
+    seed.copy_from_slice(&big_seed[..32]);
+    Ok(seed)
+}
+
+/// ed25519 seed of a secret URI, derived like Substrate's `Ed25519HDKD`.
+/// ed25519 only has hard derivation.
+pub fn ed25519_seed(uri: &SecretUri) -> Result<Zeroizing<[u8; 32]>, KeystoreError> {
+    let password = uri.password.as_ref().map(|p| p.expose_secret());
+    let mut seed = mini_secret_seed(uri.phrase.expose_secret(), password)?;
+    for junction in &uri.junctions {
+        if !junction.is_hard() {
+            return Err(KeystoreError::InvalidSecret(
+                "ed25519 accounts only support hard derivation (//)".to_string(),
+            ));
+        }
+        *seed = ("Ed25519HDKD", &*seed, junction.inner()).using_encoded(blake2_256);
+    }
+    Ok(seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const PHRASE: &str = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+    #[test]
+    fn takes_a_hex_phrase_as_the_seed() {
+        let hex_seed = format!("0x{}", "ab".repeat(32));
+        assert_eq!(*mini_secret_seed(&hex_seed, None).unwrap(), [0xab; 32]);
+    }
+
+    #[test]
+    fn seeds_depend_on_the_bip39_password() {
+        assert_ne!(
+            *mini_secret_seed(PHRASE, None).unwrap(),
+            *mini_secret_seed(PHRASE, Some("pass")).unwrap()
+        );
+    }
+
+    #[test]
+    fn ed25519_rejects_soft_derivation() {
+        let uri = SecretUri::from_str(&format!("{PHRASE}/soft")).unwrap();
+        assert!(matches!(
+            ed25519_seed(&uri),
+            Err(KeystoreError::InvalidSecret(_))
+        ));
+    }
+}
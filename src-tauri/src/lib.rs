@@ -2,6 +2,10 @@
 pub mod signing;
 
 use crate::signing::create_account::create_seed;
+use crate::signing::keystore::{
+    keystore_import, keystore_list, keystore_lock, keystore_sign_message, keystore_sign_payload,
+    keystore_unlock, Keystore,
+};
 
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -12,7 +16,17 @@ fn greet(name: &str) -> String {
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![greet, create_seed])
+        .manage(Keystore::default())
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            create_seed,
+            keystore_list,
+            keystore_import,
+            keystore_unlock,
+            keystore_lock,
+            keystore_sign_payload,
+            keystore_sign_message
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,68 @@
+use crate::signing::error::KeystoreError;
+use ed25519_dalek::{Signer as _, SigningKey};
+use serde::{Deserialize, Serialize};
+use shivarthu_keys::seed::ed25519_seed;
+use sp_core_hashing::blake2_256;
+use std::str::FromStr;
+use subxt_core::utils::{AccountId32, MultiSignature};
+use subxt_signer::{ecdsa, sr25519, SecretUri};
+
+/// Key scheme of an account, named as in the web build. Accounts saved
+/// before it was recorded are sr25519.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub enum CryptoType {
+    #[default]
+    Sr25519,
+    Ed25519,
+    Ecdsa,
+}
+
+/// Keypair of an unlocked account. Derivation matches the web build, so an
+/// account has the same address in both.
+pub enum AccountKeypair {
+    Sr25519(sr25519::Keypair),
+    Ed25519(SigningKey),
+    Ecdsa(ecdsa::Keypair),
+}
+
+impl AccountKeypair {
+    /// Keypair of a secret URI, `phrase//hard/soft///password`. A plain
+    /// phrase is a URI without derivation or BIP39 password. ed25519 and
+    /// ecdsa only have hard derivation.
+    pub fn from_uri(crypto_type: CryptoType, uri: &str) -> Result<Self, KeystoreError> {
+        let uri =
+            SecretUri::from_str(uri).map_err(|e| KeystoreError::InvalidPhrase(e.to_string()))?;
+        match crypto_type {
+            CryptoType::Sr25519 => sr25519::Keypair::from_uri(&uri)
+                .map(AccountKeypair::Sr25519)
+                .map_err(|e| KeystoreError::InvalidPhrase(e.to_string())),
+            CryptoType::Ed25519 => Ok(AccountKeypair::Ed25519(SigningKey::from_bytes(
+                &ed25519_seed(&uri)?,
+            ))),
+            CryptoType::Ecdsa => ecdsa::Keypair::from_uri(&uri)
+                .map(AccountKeypair::Ecdsa)
+                .map_err(|e| KeystoreError::InvalidPhrase(e.to_string())),
+        }
+    }
+
+    /// ecdsa accounts are the blake2 hash of the compressed public key.
+    pub fn account_id(&self) -> AccountId32 {
+        match self {
+            AccountKeypair::Sr25519(keypair) => AccountId32(keypair.public_key().0),
+            AccountKeypair::Ed25519(signing_key) => {
+                AccountId32(signing_key.verifying_key().to_bytes())
+            }
+            AccountKeypair::Ecdsa(keypair) => AccountId32(blake2_256(&keypair.public_key().0)),
+        }
+    }
+
+    pub fn sign(&self, message: &[u8]) -> MultiSignature {
+        match self {
+            AccountKeypair::Sr25519(keypair) => MultiSignature::Sr25519(keypair.sign(message).0),
+            AccountKeypair::Ed25519(signing_key) => {
+                MultiSignature::Ed25519(signing_key.sign(message).to_bytes())
+            }
+            AccountKeypair::Ecdsa(keypair) => MultiSignature::Ecdsa(keypair.sign(message).0),
+        }
+    }
+}
@@ -1,8 +1,27 @@
+use crate::signing::error::KeystoreError;
 use bip39::{Language, Mnemonic};
+use zeroize::Zeroizing;
 
-#[tauri::command]
-pub fn create_seed() -> String {
+/// Word counts a new seed phrase can have.
+pub const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+pub fn generate_phrase(words: usize) -> Result<Zeroizing<String>, KeystoreError> {
+    if !WORD_COUNTS.contains(&words) {
+        return Err(KeystoreError::InvalidPhrase(format!(
+            "A seed phrase has 12, 15, 18, 21 or 24 words, not {words}"
+        )));
+    }
     let mut rng = bip39::rand::thread_rng();
-    let m = Mnemonic::generate_in_with(&mut rng, Language::English, 12).unwrap();
-    format!("{}", m)
+    let mnemonic = Mnemonic::generate_in_with(&mut rng, Language::English, words)
+        .map_err(|e| KeystoreError::InvalidPhrase(e.to_string()))?;
+    Ok(Zeroizing::new(mnemonic.to_string()))
+}
+
+/// Generates a seed phrase of `words` words, 12 when not given. This is the
+/// only time the phrase leaves the backend: the webview shows it for backup
+/// and imports it once the user confirms it, and the copy here is wiped once
+/// it has been sent.
+#[tauri::command]
+pub fn create_seed(words: Option<usize>) -> Result<Zeroizing<String>, KeystoreError> {
+    generate_phrase(words.unwrap_or(12))
 }
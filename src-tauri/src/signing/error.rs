@@ -0,0 +1,31 @@
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum KeystoreError {
+    /// Errors of the seed derivation and encryption shared with the web
+    /// build.
+    #[error(transparent)]
+    Seed(#[from] shivarthu_keys::error::KeystoreError),
+    #[error("Corrupted keystore file: {0}")]
+    Corrupted(String),
+    #[error("Invalid account phrase: {0}")]
+    InvalidPhrase(String),
+    #[error("Account {0} already exists")]
+    AlreadyExists(String),
+    #[error("Unknown account {0}")]
+    UnknownAccount(String),
+    #[error("Account {0} is locked")]
+    Locked(String),
+    #[error("Invalid signing payload: {0}")]
+    InvalidPayload(String),
+    #[error("Keystore file error: {0}")]
+    Io(String),
+}
+
+// Commands return errors to the webview as plain messages.
+impl Serialize for KeystoreError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
@@ -0,0 +1,260 @@
+use crate::signing::account_keypair::{AccountKeypair, CryptoType};
+use crate::signing::error::KeystoreError;
+use serde::{Deserialize, Serialize};
+use shivarthu_keys::encrypted_seed::EncryptedSeed;
+use sp_core_hashing::blake2_256;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use subxt_core::ext::codec::Encode;
+use tauri::{AppHandle, Manager, State};
+use zeroize::Zeroizing;
+
+const KEYSTORE_FILE: &str = "keystore.json";
+
+#[derive(Serialize, Deserialize, Clone)]
+struct StoredAccount {
+    address: String,
+    name: String,
+    #[serde(default)]
+    crypto_type: CryptoType,
+    encrypted_seed: EncryptedSeed,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct KeystoreFile {
+    accounts: Vec<StoredAccount>,
+}
+
+/// What the webview gets to see of an account.
+#[derive(Serialize, Clone)]
+pub struct KeystoreAccount {
+    pub address: String,
+    pub name: String,
+    pub crypto_type: CryptoType,
+    pub unlocked: bool,
+}
+
+/// Keypairs of the unlocked accounts. Seeds never leave this process; the
+/// webview only receives addresses and signatures.
+#[derive(Default)]
+pub struct Keystore {
+    unlocked: Mutex<HashMap<String, AccountKeypair>>,
+}
+
+impl Keystore {
+    fn is_unlocked(&self, address: &str) -> bool {
+        self.unlocked
+            .lock()
+            .map(|unlocked| unlocked.contains_key(address))
+            .unwrap_or(false)
+    }
+
+    fn insert(&self, address: String, keypair: AccountKeypair) {
+        if let Ok(mut unlocked) = self.unlocked.lock() {
+            unlocked.insert(address, keypair);
+        }
+    }
+}
+
+fn keystore_path(app: &AppHandle) -> Result<PathBuf, KeystoreError> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| KeystoreError::Io(e.to_string()))?;
+    fs::create_dir_all(&dir).map_err(|e| KeystoreError::Io(e.to_string()))?;
+    Ok(dir.join(KEYSTORE_FILE))
+}
+
+fn read_keystore(app: &AppHandle) -> Result<KeystoreFile, KeystoreError> {
+    let path = keystore_path(app)?;
+    if !path.exists() {
+        return Ok(KeystoreFile::default());
+    }
+    let json = fs::read_to_string(path).map_err(|e| KeystoreError::Io(e.to_string()))?;
+    serde_json::from_str(&json).map_err(|e| KeystoreError::Corrupted(e.to_string()))
+}
+
+/// Writes a temporary file next to the keystore and renames it over the old
+/// one, so a crash mid-write cannot leave a truncated keystore behind.
+fn write_keystore(app: &AppHandle, keystore_file: &KeystoreFile) -> Result<(), KeystoreError> {
+    let json = serde_json::to_string_pretty(keystore_file)
+        .map_err(|e| KeystoreError::Io(e.to_string()))?;
+    let path = keystore_path(app)?;
+    let temp_path = path.with_extension("json.tmp");
+    let mut file = fs::File::create(&temp_path).map_err(|e| KeystoreError::Io(e.to_string()))?;
+    file.write_all(json.as_bytes())
+        .and_then(|_| file.sync_all())
+        .map_err(|e| KeystoreError::Io(e.to_string()))?;
+    fs::rename(&temp_path, &path).map_err(|e| KeystoreError::Io(e.to_string()))
+}
+
+/// Encrypts and saves the secret URI `phrase`, leaving the new account
+/// unlocked.
+fn add_account(
+    app: &AppHandle,
+    keystore: &Keystore,
+    name: String,
+    crypto_type: CryptoType,
+    phrase: &str,
+    password: &str,
+) -> Result<KeystoreAccount, KeystoreError> {
+    let keypair = AccountKeypair::from_uri(crypto_type, phrase)?;
+    let address = keypair.account_id().to_string();
+
+    let mut keystore_file = read_keystore(app)?;
+    if keystore_file.accounts.iter().any(|a| a.address == address) {
+        return Err(KeystoreError::AlreadyExists(address));
+    }
+    keystore_file.accounts.push(StoredAccount {
+        address: address.clone(),
+        name: name.clone(),
+        crypto_type,
+        encrypted_seed: EncryptedSeed::encrypt(phrase, password)?,
+    });
+    write_keystore(app, &keystore_file)?;
+
+    keystore.insert(address.clone(), keypair);
+    Ok(KeystoreAccount {
+        address,
+        name,
+        crypto_type,
+        unlocked: true,
+    })
+}
+
+#[tauri::command]
+pub fn keystore_list(
+    app: AppHandle,
+    keystore: State<'_, Keystore>,
+) -> Result<Vec<KeystoreAccount>, KeystoreError> {
+    Ok(read_keystore(&app)?
+        .accounts
+        .into_iter()
+        .map(|a| KeystoreAccount {
+            unlocked: keystore.is_unlocked(&a.address),
+            address: a.address,
+            name: a.name,
+            crypto_type: a.crypto_type,
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn keystore_import(
+    app: AppHandle,
+    keystore: State<'_, Keystore>,
+    name: String,
+    crypto_type: Option<CryptoType>,
+    phrase: String,
+    password: String,
+) -> Result<KeystoreAccount, KeystoreError> {
+    let phrase = Zeroizing::new(phrase);
+    let password = Zeroizing::new(password);
+    add_account(
+        &app,
+        &keystore,
+        name,
+        crypto_type.unwrap_or_default(),
+        phrase.trim(),
+        &password,
+    )
+}
+
+#[tauri::command]
+pub async fn keystore_unlock(
+    app: AppHandle,
+    keystore: State<'_, Keystore>,
+    address: String,
+    password: String,
+) -> Result<(), KeystoreError> {
+    let password = Zeroizing::new(password);
+    let stored = read_keystore(&app)?
+        .accounts
+        .into_iter()
+        .find(|a| a.address == address)
+        .ok_or_else(|| KeystoreError::UnknownAccount(address.clone()))?;
+    let phrase = stored.encrypted_seed.decrypt(&password)?;
+    keystore.insert(
+        address,
+        AccountKeypair::from_uri(stored.crypto_type, &phrase)?,
+    );
+    Ok(())
+}
+
+/// Locks `address`, or every account when none is given.
+#[tauri::command]
+pub fn keystore_lock(keystore: State<'_, Keystore>, address: Option<String>) {
+    if let Ok(mut unlocked) = keystore.unlocked.lock() {
+        match address {
+            Some(address) => {
+                unlocked.remove(&address);
+            }
+            None => unlocked.clear(),
+        }
+    }
+}
+
+const BYTES_PREFIX: &str = "<Bytes>";
+const BYTES_SUFFIX: &str = "</Bytes>";
+
+/// Signs `message` with an unlocked account, encoding the signature as a
+/// SCALE `MultiSignature` of the account's key type.
+fn sign_with(keystore: &Keystore, address: &str, message: &[u8]) -> Result<Vec<u8>, KeystoreError> {
+    let unlocked = keystore
+        .unlocked
+        .lock()
+        .map_err(|e| KeystoreError::Io(e.to_string()))?;
+    let keypair = unlocked
+        .get(address)
+        .ok_or_else(|| KeystoreError::Locked(address.to_string()))?;
+    Ok(keypair.sign(message).encode())
+}
+
+/// Signer payloads longer than this are signed as their blake2_256 hash,
+/// as Substrate does.
+const MAX_UNHASHED_PAYLOAD_LEN: usize = 256;
+
+/// Signs the signer payload of a transaction: the SCALE encoded call, which
+/// starts with its pallet and call index, followed by the transaction
+/// extensions. Payloads wrapped like a message are refused, so a signed
+/// message cannot be passed off as one.
+#[tauri::command]
+pub fn keystore_sign_payload(
+    keystore: State<'_, Keystore>,
+    address: String,
+    payload: Vec<u8>,
+) -> Result<Vec<u8>, KeystoreError> {
+    if payload.len() < 2 {
+        return Err(KeystoreError::InvalidPayload(
+            "A transaction payload starts with a pallet and call index".to_string(),
+        ));
+    }
+    if payload.starts_with(BYTES_PREFIX.as_bytes()) {
+        return Err(KeystoreError::InvalidPayload(
+            "A message cannot be signed as a transaction".to_string(),
+        ));
+    }
+    if payload.len() > MAX_UNHASHED_PAYLOAD_LEN {
+        return sign_with(&keystore, &address, &blake2_256(&payload));
+    }
+    sign_with(&keystore, &address, &payload)
+}
+
+/// Signs `message` wrapped in `<Bytes>…</Bytes>`, as polkadot.js does, so
+/// it can never be a valid transaction payload.
+#[tauri::command]
+pub fn keystore_sign_message(
+    keystore: State<'_, Keystore>,
+    address: String,
+    message: String,
+) -> Result<Vec<u8>, KeystoreError> {
+    let wrapped = if message.starts_with(BYTES_PREFIX) && message.ends_with(BYTES_SUFFIX) {
+        message
+    } else {
+        format!("{BYTES_PREFIX}{message}{BYTES_SUFFIX}")
+    };
+    sign_with(&keystore, &address, wrapped.as_bytes())
+}
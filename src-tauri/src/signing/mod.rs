@@ -1 +1,4 @@
+pub mod account_keypair;
 pub mod create_account;
+pub mod error;
+pub mod keystore;
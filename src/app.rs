@@ -1,8 +1,14 @@
 use crate::components::common::global_state::GlobalState;
 use crate::components::navigation::nav::Nav;
 use crate::router::RouterApp;
+use crate::services::chain_client::ChainClient;
+use crate::services::chain_head::ChainHead;
+use crate::services::endpoints::EndpointStore;
+use crate::services::session::SessionManager;
+use codee::string::JsonSerdeCodec;
 use leptos::task::spawn_local;
 use leptos::{ev::SubmitEvent, prelude::*};
+use leptos_use::storage::use_local_storage;
 use reactive_stores::Store;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
@@ -11,7 +17,14 @@ use web_sys::{window, HtmlElement};
 
 #[component]
 pub fn App() -> impl IntoView {
-    provide_context(Store::new(GlobalState::default()));
+    let state = Store::new(GlobalState::default());
+    provide_context(state);
+    provide_context(SessionManager::new(state));
+    let (endpoint_store, set_endpoint_store, _reset_endpoint_store) =
+        use_local_storage::<EndpointStore, JsonSerdeCodec>("endpoint-store-state");
+    let chain_client = ChainClient::new(endpoint_store, set_endpoint_store);
+    provide_context(chain_client);
+    provide_context(ChainHead::new(chain_client));
 
     let window = window().expect("should have a Window");
 
@@ -1,9 +1,17 @@
+use crate::services::error::AppError;
 use gloo::net::http::{Request, Response};
 
-pub async fn ipfs_fetch_response(hash: &str, ipfs_address: &str) -> Response {
+pub async fn ipfs_fetch_response(hash: &str, ipfs_address: &str) -> Result<Response, AppError> {
     let resp = Request::get(&format!("{}{}", ipfs_address, hash))
         .send()
-        .await
-        .unwrap();
-    resp
+        .await?;
+    if !resp.ok() {
+        return Err(AppError::Ipfs(format!(
+            "{} returned {} for {}",
+            ipfs_address,
+            resp.status(),
+            hash
+        )));
+    }
+    Ok(resp)
 }
@@ -0,0 +1,56 @@
+use crate::services::chain_head::ChainHead;
+use leptos::prelude::*;
+use wasm_bindgen::JsValue;
+
+fn format_remaining(remaining_ms: f64) -> String {
+    let total_seconds = (remaining_ms / 1000.0).ceil() as u64;
+    let days = total_seconds / 86_400;
+    let hours = total_seconds % 86_400 / 3_600;
+    let minutes = total_seconds % 3_600 / 60;
+    let seconds = total_seconds % 60;
+    if days > 0 {
+        format!("{days}d {hours}h {minutes}m")
+    } else if hours > 0 {
+        format!("{hours}h {minutes:02}m {seconds:02}s")
+    } else {
+        format!("{minutes}m {seconds:02}s")
+    }
+}
+
+fn format_local_time(ms: f64) -> String {
+    js_sys::Date::new(&JsValue::from_f64(ms))
+        .to_locale_string("default", &JsValue::UNDEFINED)
+        .into()
+}
+
+/// Shows `end_block` with the estimated time left until it is finalized.
+#[component]
+pub fn BlockCountdown(end_block: u32) -> impl IntoView {
+    let chain_head = expect_context::<ChainHead>();
+    let now_ms = chain_head.now_ms();
+
+    let countdown = move || {
+        chain_head.deadline(end_block).map(|deadline| {
+            let remaining_ms = deadline.deadline_ms - now_ms();
+            if deadline.blocks_left == 0 {
+                "ended".to_string()
+            } else if remaining_ms <= 0.0 {
+                format!("{} blocks left, any moment now", deadline.blocks_left)
+            } else {
+                format!(
+                    "{} blocks left, about {} (around {})",
+                    deadline.blocks_left,
+                    format_remaining(remaining_ms),
+                    format_local_time(deadline.deadline_ms)
+                )
+            }
+        })
+    };
+
+    view! {
+        <span id="end-period-time">{end_block}</span>
+        <span class="ml-2 text-sm text-gray-500 dark:text-gray-400" id="end-period-countdown">
+            {countdown}
+        </span>
+    }
+}
@@ -0,0 +1,44 @@
+use leptos::prelude::*;
+
+/// `ErrorBoundary` with the app's alert styling and an optional Retry button.
+///
+/// The boundary clears itself once the children render without errors, so
+/// `on_retry` only needs to re-run the failed loader.
+#[component]
+pub fn AppErrorBoundary(
+    #[prop(optional, into)] on_retry: Option<Callback<()>>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <ErrorBoundary fallback=move |errors| {
+            view! {
+                <div
+                    role="alert"
+                    class="flex flex-col sm:flex-row sm:items-center sm:justify-between gap-3 p-4 border-l-4 border-red-500 bg-red-100 text-red-800 dark:bg-red-900 dark:text-red-200 rounded-xl shadow-md"
+                >
+                    <ul class="list-disc pl-4 space-y-1">
+                        {move || {
+                            errors
+                                .get()
+                                .into_iter()
+                                .map(|(_, e)| view! { <li>{e.to_string()}</li> })
+                                .collect::<Vec<_>>()
+                        }}
+                    </ul>
+                    {on_retry
+                        .map(|on_retry| {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="px-3 py-1 bg-red-600 text-white text-sm rounded hover:bg-red-700"
+                                    on:click=move |_| on_retry.run(())
+                                >
+                                    Retry
+                                </button>
+                            }
+                        })}
+                </div>
+            }
+        }>{children()}</ErrorBoundary>
+    }
+}
@@ -1,8 +1,16 @@
+use crate::services::account_secret::CryptoType;
+use crate::services::signer::SignerKind;
 use reactive_stores::Store;
+use zeroize::Zeroizing;
 
 #[derive(Clone, Debug, Default, Store)]
 pub struct GlobalState {
     pub account_address: String,
-    pub mnemonic_phrase: Option<String>,
+    /// Secret of the unlocked local keystore account. Every copy is wiped
+    /// when dropped.
+    pub mnemonic_phrase: Option<Zeroizing<String>>,
     pub phase_exists_in_state: bool,
+    pub signer_kind: SignerKind,
+    /// Key scheme of the unlocked local keystore account.
+    pub crypto_type: CryptoType,
 }
@@ -1,2 +1,4 @@
+pub mod block_countdown;
+pub mod error_view;
 pub mod global_state;
 pub mod spinner;
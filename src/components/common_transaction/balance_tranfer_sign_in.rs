@@ -1,8 +1,7 @@
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::components::signing::sign_transaction_fn::SignTransactionFn;
-use crate::services::common_services::polkadot;
+use crate::services::common_services::{account_id_from_str, polkadot};
 use leptos::prelude::*;
-use std::str::FromStr;
-use subxt::utils::AccountId32;
 
 #[component]
 pub fn SignTransaction(transfer_address: String, transfer_balance: u128) -> impl IntoView {
@@ -11,12 +10,14 @@ pub fn SignTransaction(transfer_address: String, transfer_balance: u128) -> impl
 
 #[component]
 pub fn ExtensionSignIn(transfer_address: String, transfer_balance: u128) -> impl IntoView {
-    let account_id32 = AccountId32::from_str(&transfer_address).unwrap();
+    let sign_transaction = account_id_from_str(&transfer_address).map(|account_id32| {
+        let tx = Box::new(polkadot::tx().balances().transfer_allow_death(
+            subxt::utils::MultiAddress::Id(account_id32),
+            transfer_balance,
+        ));
 
-    let tx = Box::new(polkadot::tx().balances().transfer_allow_death(
-        subxt::utils::MultiAddress::Id(account_id32),
-        transfer_balance,
-    ));
+        view! { <SignTransactionFn tx=tx /> }
+    });
 
-    view! { <SignTransactionFn tx=tx /> }
+    view! { <AppErrorBoundary>{sign_transaction}</AppErrorBoundary> }
 }
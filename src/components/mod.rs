@@ -4,5 +4,7 @@ pub mod common_transaction;
 pub mod markdown;
 pub mod navigation;
 pub mod schelling_game;
+pub mod settings;
 pub mod shared_storage;
 pub mod signing;
+pub mod upload;
@@ -1,5 +1,7 @@
 use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
 use crate::components::signing::accounts_store::AccountStore;
+use crate::services::session::SessionManager;
+use crate::services::signer::SignerKind;
 use codee::string::JsonSerdeCodec;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
@@ -24,6 +26,9 @@ pub fn AccountNav() -> impl IntoView {
     let state = expect_context::<Store<GlobalState>>();
 
     let account = state.account_address();
+    let signer_kind = state.signer_kind();
+    let session = expect_context::<SessionManager>();
+    let unlocked = session.is_unlocked();
 
     let UseClipboardReturn {
         is_supported,
@@ -45,6 +50,11 @@ pub fn AccountNav() -> impl IntoView {
                     view! {
                         <>
                             <span>{shortened_id}</span>
+                            <Show when=move || signer_kind.get() == SignerKind::WatchOnly>
+                                <span class="ml-2 text-xs text-gray-500 dark:text-gray-400">
+                                    watch-only
+                                </span>
+                            </Show>
 
                             <button on:click={
                                 let copy = copy.clone();
@@ -59,6 +69,18 @@ pub fn AccountNav() -> impl IntoView {
                                     Copied!
                                 </Show>
                             </button>
+                            <Show when=move || unlocked.get()>
+                                <button
+                                    class="ml-2"
+                                    title="Lock"
+                                    on:click=move |ev| {
+                                        ev.stop_propagation();
+                                        session.lock();
+                                    }
+                                >
+                                    <Icon icon=icondata::AiLockOutlined />
+                                </button>
+                            </Show>
                         </>
                     }
                         .into_any()
@@ -1,57 +1,51 @@
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
-use crate::constants::constant::NODE_URL;
-use crate::services::common_services::polkadot;
+use crate::services::chain_client::ChainClient;
+use crate::services::common_services::{account_id_from_str, polkadot};
+use crate::services::error::AppError;
 use leptos::prelude::*;
-use leptos::task::spawn_local;
 use reactive_stores::Store;
-use std::str::FromStr;
-use subxt::utils::AccountId32;
-use subxt::{OnlineClient, PolkadotConfig};
+
+async fn load_data(chain_client: ChainClient, account: String) -> Result<Option<u128>, AppError> {
+    // account is not empyt string
+    if account.is_empty() {
+        return Ok(None);
+    }
+
+    let account_id32 = account_id_from_str(&account)?;
+
+    let client = chain_client.online().await?;
+    let balance_storage = polkadot::storage().system().account(account_id32);
+    let balance_details = client
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&balance_storage)
+        .await?;
+
+    // log!(format!("{:?}", balance_details.data.free));
+    Ok(balance_details.map(|balance_details| balance_details.data.free))
+}
 
 #[component]
 pub fn Balance() -> impl IntoView {
-    let (balance, set_balance) = signal(None);
     let state = expect_context::<Store<GlobalState>>();
+    let chain_client = expect_context::<ChainClient>();
 
     let account = state.account_address();
 
-    Effect::new(move |_| {
-        // account is not empyt string
-        if account.get().is_empty() {
-            return;
-        }
-
-        spawn_local(async move {
-            let account_id32 = AccountId32::from_str(&account.get()).unwrap();
-
-            let client = subxt::client::OnlineClient::<PolkadotConfig>::from_url(NODE_URL)
-                .await
-                .unwrap();
-            let balance_storage = polkadot::storage().system().account(account_id32);
-            let balance_details = client
-                .storage()
-                .at_latest()
-                .await
-                .unwrap()
-                .fetch(&balance_storage)
-                .await
-                .unwrap();
-
-            if let Some(balance_details) = balance_details {
-                // log!(format!("{:?}", balance_details.data.free));
-                set_balance(Some(balance_details.data.free))
-            }
-        });
-    });
+    let balance = LocalResource::new(move || load_data(chain_client, account.get()));
 
     view! {
         <div>
-            {move || {
-                match balance.get() {
-                    Some(free) => view!{<span>{format!("{} SHIV", free as f64 / 10f64.powi(10))}</span>}.into_any(), // adjust decimals based on your chain
-                    None =>  view!{<span>{format!{"Balance not set"}}</span>}.into_any(),
-                }
-            }}
+            <AppErrorBoundary on_retry=move |_: ()| balance.refetch()>
+                {move || {
+                    balance.get().transpose().map(|free| match free.flatten() {
+                        Some(free) => view!{<span>{format!("{} SHIV", free as f64 / 10f64.powi(10))}</span>}.into_any(), // adjust decimals based on your chain
+                        None =>  view!{<span>{format!{"Balance not set"}}</span>}.into_any(),
+                    })
+                }}
+            </AppErrorBoundary>
         </div>
     }
 }
@@ -0,0 +1,29 @@
+use crate::services::chain_client::{ChainClient, ConnectionStatus};
+use leptos::prelude::*;
+
+#[component]
+pub fn ConnectionStatusBadge() -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
+    let status = chain_client.status();
+
+    view! {
+        {move || {
+            let (dot_class, label) = match status.get() {
+                ConnectionStatus::Connecting => ("bg-yellow-500", "Connecting...".to_string()),
+                ConnectionStatus::Connected => ("bg-green-500", "Connected".to_string()),
+                ConnectionStatus::Reconnecting { attempt, retry_in_ms } => {
+                    (
+                        "bg-red-500",
+                        format!("Reconnecting (attempt {}, retry in {}s)", attempt, retry_in_ms / 1000),
+                    )
+                }
+            };
+            view! {
+                <span class="inline-flex items-center gap-2 py-2 px-4 text-sm text-gray-700 dark:text-white">
+                    <span class=format!("inline-block w-2 h-2 rounded-full {}", dot_class)></span>
+                    {label}
+                </span>
+            }
+        }}
+    }
+}
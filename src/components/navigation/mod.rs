@@ -1,3 +1,4 @@
 pub mod account_nav;
 pub mod nav;
 pub mod balance;
+pub mod connection_status;
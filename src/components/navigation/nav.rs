@@ -3,6 +3,7 @@ use leptos::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::{window, HtmlElement};
 use crate::components::navigation::balance::Balance;
+use crate::components::navigation::connection_status::ConnectionStatusBadge;
 
 #[component]
 pub fn Nav() -> impl IntoView {
@@ -119,6 +120,20 @@ fn navbar_items() -> impl IntoView {
                         "Add Account"
                     </a>
 
+                    <a
+                        href="/import-json-account"
+                        class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+                    >
+                        "Import JSON Account"
+                    </a>
+
+                    <a
+                        href="/export-json-account"
+                        class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+                    >
+                        "Export JSON Account"
+                    </a>
+
                     <a
                         href="/balance-transfer"
                         class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
@@ -131,6 +146,30 @@ fn navbar_items() -> impl IntoView {
                     >
                         "Delete Account"
                     </a>
+                    <a
+                        href="/manage-accounts"
+                        class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+                    >
+                        "Manage Accounts"
+                    </a>
+                    <a
+                        href="/sign-message"
+                        class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+                    >
+                        "Sign Message"
+                    </a>
+                    <a
+                        href="/verify-message"
+                        class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+                    >
+                        "Verify Message"
+                    </a>
+                    <a
+                        href="/vote-vault"
+                        class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+                    >
+                        "Vote Vault"
+                    </a>
 
                     <a
                         href="/sign-out"
@@ -186,6 +225,27 @@ fn navbar_items() -> impl IntoView {
                         "Positive Work Post"
                         </a>
 
+                        <a
+                            href="/profile-validation/add-profile"
+                            class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+                        >
+                        "Add Profile"
+                        </a>
+
+                        <a
+                            href="/departments/create-department"
+                            class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+                        >
+                        "Create Department"
+                        </a>
+
+                        <a
+                            href="/juror-dashboard"
+                            class="block py-2 w-full px-4 text-gray-700 hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+                        >
+                        "Juror Dashboard"
+                        </a>
+
 
                     </div>
             </div>
@@ -240,6 +300,22 @@ fn navbar_items() -> impl IntoView {
                 "Sign In"
             </a>
 
+            <a
+                href="/settings/endpoints"
+                class="block py-2 px-4 text-gray-700 rounded hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+            >
+                "Endpoints"
+            </a>
+
+            <a
+                href="/settings/session"
+                class="block py-2 px-4 text-gray-700 rounded hover:bg-gray-100 dark:text-white dark:hover:bg-gray-700"
+            >
+                "Session"
+            </a>
+
+            <ConnectionStatusBadge />
+
             <button
                 class="bg-gray-400 dark:bg-gray-600 text-white py-2 px-4 rounded-xl hover:bg-gray-500 dark:hover:bg-gray-500"
                 on:click=toggle_dark_mode
@@ -1,28 +1,32 @@
-use crate::components::schelling_game::project_tips::apply_jurors_sign_in::SignTransaction;
-use crate::components::schelling_game::project_tips::change_period::ChangePeriod;
-use crate::components::schelling_game::project_tips::rpc::staking_end_block::StakingEndBlock;
-use crate::components::schelling_game::project_tips::storage::get_period::GetPeriod;
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::change_period::ChangePeriod;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::game_transaction::GameTransaction;
+use crate::components::schelling_game::common::get_period::GetPeriod;
+use crate::components::schelling_game::common::period_end_block::{PeriodEnd, PeriodEndBlock};
 use crate::services::common_imp::View;
-use crate::services::error::ErrorString;
+use crate::services::error::AppError;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 
 #[component]
-pub fn ApplyJurors(project_id: u64) -> impl IntoView {
-    // gloo::console::log!(project_id());
+pub fn ApplyJurors<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
     let (current_view, set_current_view) = signal(View::Form);
-    let (juror_stake, set_juror_stake) = signal::<Result<u128, ErrorString>>(Ok(0));
+    let (juror_stake, set_juror_stake) = signal::<Result<u128, AppError>>(Ok(0));
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
-
-        set_current_view(View::Success);
+        if juror_stake().is_ok() {
+            set_current_view(View::Success);
+        }
     };
 
     let stake_value = move |value: String| {
-        let stake = value.parse::<u128>().expect("Invalid input");
-        gloo::console::log!(stake);
+        let stake = value
+            .parse::<u128>()
+            .map_err(|e| AppError::Validation(format!("Invalid juror stake: {e}")));
+        gloo::console::log!(format!("{:?}", stake));
 
-        set_juror_stake(Ok(stake));
+        set_juror_stake(stake);
     };
 
     let render_view = move || {
@@ -31,9 +35,9 @@ pub fn ApplyJurors(project_id: u64) -> impl IntoView {
             view! {
                 <div class="max-w-5xl mx-auto max-md:mx-10">
                     <div class="text-gray-900 dark:text-white">
-                        <GetPeriod project_id=project_id.clone() />
-                        <StakingEndBlock project_id=project_id.clone() />
-                        <ChangePeriod project_id=project_id.clone() />
+                        <GetPeriod<K> id=id.clone() />
+                        <PeriodEndBlock<K> id=id.clone() period=PeriodEnd::Staking />
+                        <ChangePeriod<K> id=id.clone() />
                     </div>
                     <form
 
@@ -64,13 +68,15 @@ pub fn ApplyJurors(project_id: u64) -> impl IntoView {
                             Submit
                         </button>
                     </form>
+                    <br />
+                    <AppErrorBoundary>{move || juror_stake().map(|_| ())}</AppErrorBoundary>
                 </div>
             }.into_any()
         }
         View::Success => {
             view! {
                 <div>
-                    <SignTransaction stake=juror_stake().unwrap() project_id=project_id.clone() />
+                    <GameTransaction tx=K::apply_jurors(&id, juror_stake().unwrap_or_default()) />
 
                 </div>
             }.into_any()
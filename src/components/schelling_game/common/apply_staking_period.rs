@@ -1,13 +1,12 @@
 use crate::components::navigation::nav::Nav;
-use crate::components::schelling_game::project_tips::apply_staking_period_sign_in::SignTransaction;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::game_transaction::GameTransaction;
 use crate::services::common_imp::View;
-use crate::services::error::ErrorString;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 
 #[component]
-pub fn ApplyStakingPeriod(project_id: u64) -> impl IntoView {
-    // gloo::console::log!(project_id());
+pub fn ApplyStakingPeriod<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
     let (current_view, set_current_view) = signal(View::Form);
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
@@ -42,7 +41,7 @@ pub fn ApplyStakingPeriod(project_id: u64) -> impl IntoView {
             view! {
                 <div>
                     <Nav />
-                    <SignTransaction project_id=project_id.clone() />
+                    <GameTransaction tx=K::apply_staking_period(&id) />
                 </div>
             }.into_any()
         }
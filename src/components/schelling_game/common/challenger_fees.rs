@@ -0,0 +1,38 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::services::chain_client::ChainClient;
+use crate::services::error::AppError;
+use leptos::prelude::*;
+
+async fn load_data<K: SchellingGameKind>(chain_client: ChainClient) -> Result<u128, AppError> {
+    let client = chain_client.online().await?;
+    let challenger_fee_storage = subxt::dynamic::storage(K::PALLET, "RegistrationChallengeFee", ());
+
+    client
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&challenger_fee_storage)
+        .await?
+        .as_type::<u128>()
+        .map_err(|e| AppError::Decode(e.to_string()))
+}
+
+/// Fee to challenge an entry of a `K` game.
+#[component]
+pub fn ChallengerFees<K: SchellingGameKind>() -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
+
+    let challenger_fee = LocalResource::new(move || load_data::<K>(chain_client));
+
+    view! {
+        <AppErrorBoundary on_retry=move |_: ()| challenger_fee.refetch()>
+            {move || {
+                challenger_fee
+                    .get()
+                    .transpose()
+                    .map(|fee| fee.map(|fee| view! { <div>Challenger fee: {fee}</div> }))
+            }}
+        </AppErrorBoundary>
+    }
+}
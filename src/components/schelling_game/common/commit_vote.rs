@@ -0,0 +1,300 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
+use crate::components::schelling_game::common::change_period::ChangePeriod;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::game_transaction::GameTransaction;
+use crate::components::schelling_game::common::get_period::GetPeriod;
+use crate::components::schelling_game::common::period_end_block::{PeriodEnd, PeriodEndBlock};
+use crate::components::signing::accounts_store::AccountStore;
+use crate::services::chain_head::ChainHead;
+use crate::services::common_imp::View;
+use crate::services::error::AppError;
+use crate::services::signer::SignerKind;
+use crate::services::vote_vault::{
+    before_key_derivation, generate_salt, vote_commit, VaultEntry, VoteSecret, VoteVault,
+    VOTE_VAULT_KEY,
+};
+use codee::string::JsonSerdeCodec;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::storage::use_local_storage;
+use reactive_stores::Store;
+
+/// Commits a vote with a generated salt. The choice and salt are saved in
+/// the vote vault, encrypted under the password, before the commit is
+/// signed, so the reveal can be filled in later. Votes saved by earlier
+/// commits are kept in case this one is never submitted. The password is
+/// entered twice, as a typo would lock the vote away.
+#[component]
+pub fn CommitVote<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+    let account = state.account_address();
+    let signer_kind = state.signer_kind();
+    let current_block = expect_context::<ChainHead>().current_block();
+    let (_vault, set_vault, _reset_vault) =
+        use_local_storage::<VoteVault, JsonSerdeCodec>(VOTE_VAULT_KEY);
+    let (account_store, _set_account_store, _reset_account_store) =
+        use_local_storage::<AccountStore, JsonSerdeCodec>("account-store-state");
+    let (current_view, set_current_view) = signal(View::Form);
+    let (hash, set_hash) = signal::<Result<Option<[u8; 32]>, AppError>>(Ok(None));
+    // A score slider always holds a value, so it starts in the middle.
+    let (choice, set_choice) = signal(K::SCORE_GAME.then_some(K::CHOICES.len() / 2));
+    let (salt, set_salt) = signal(generate_salt());
+    let (password, set_password) = signal(String::new());
+    let (confirm_password, set_confirm_password) = signal(String::new());
+    let (saving, set_saving) = signal(false);
+    let game_id = StoredValue::new(id.to_string());
+
+    // A local keystore account must also unlock with the password.
+    let check_password = move |address: &str, password: &str| -> Result<(), AppError> {
+        if signer_kind.get_untracked() != SignerKind::LocalKeystore {
+            return Ok(());
+        }
+        account_store
+            .with_untracked(|store| {
+                store
+                    .accounts
+                    .iter()
+                    .find(|a| a.account_address == address && a.signer == SignerKind::LocalKeystore)
+                    .map(|a| a.unlock(password).map(|_| ()))
+            })
+            .unwrap_or(Ok(()))
+            .map_err(|e| AppError::Validation(e.to_string()))
+    };
+
+    let save_vote = move || async move {
+        let address = account.get_untracked();
+        if address.is_empty() {
+            return Err(AppError::Validation(
+                "Sign in before committing, the vote is saved for your account".to_string(),
+            ));
+        }
+        if signer_kind.get_untracked() == SignerKind::WatchOnly {
+            return Err(AppError::Validation(
+                "Watch-only accounts cannot vote".to_string(),
+            ));
+        }
+        let (choice, _) = choice()
+            .and_then(|index| K::CHOICES.get(index))
+            .ok_or_else(|| AppError::Validation("Select a choice".to_string()))?;
+        let salt = salt().map_err(|e| AppError::Validation(e.to_string()))?;
+        if password() != confirm_password() {
+            return Err(AppError::Validation("Passwords do not match".to_string()));
+        }
+        before_key_derivation().await;
+        check_password(&address, &password())?;
+
+        let choice = choice.to_string();
+        let commit = vote_commit(&choice, &salt);
+        let secret = VoteSecret {
+            choice,
+            salt,
+            commit: format!("0x{}", hex::encode(commit)),
+            block: current_block.get_untracked(),
+        };
+        let entry = VaultEntry::seal(
+            K::PALLET,
+            &game_id.get_value(),
+            &address,
+            &secret,
+            &password(),
+        )
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+        set_vault.update(|vault| vault.insert(entry));
+        Ok::<_, AppError>(commit)
+    };
+
+    let submit_click = move |e: SubmitEvent| {
+        e.prevent_default();
+        if saving.get_untracked() {
+            return;
+        }
+        set_saving(true);
+        spawn_local(async move {
+            match save_vote().await {
+                Ok(hash_data) => {
+                    set_hash(Ok(Some(hash_data)));
+                    set_password(String::new());
+                    set_confirm_password(String::new());
+                    set_current_view(View::Success);
+                }
+                Err(e) => set_hash(Err(e)),
+            }
+            set_saving(false);
+        });
+    };
+
+    let render_view = move || {
+        match current_view() {
+                View::Form => {
+                    view! {
+                        <div class="max-w-5xl mx-auto max-md:mx-10">
+                            <div class="text-gray-900 dark:text-white">
+                                <GetPeriod<K> id=id.clone() />
+                                <PeriodEndBlock<K> id=id.clone() period=PeriodEnd::Commit />
+                                <ChangePeriod<K> id=id.clone() />
+                            </div>
+                            <div class="text-gray-900 dark:text-white"></div>
+                            <form
+
+                                id="commit-vote-submit-from"
+                                on:submit=submit_click
+                            >
+                                <div class="mb-5">
+                                    <label
+                                        for="commit-choice"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        {if K::SCORE_GAME { "Score" } else { "Choice" }}
+                                    </label>
+                                    {if K::SCORE_GAME {
+                                        view! {
+                                            <div class="flex items-center gap-3">
+                                                <input
+                                                    type="range"
+                                                    id="commit-choice"
+                                                    class="w-full accent-blue-600"
+                                                    min="0"
+                                                    max=(K::CHOICES.len() - 1).to_string()
+                                                    step="1"
+                                                    prop:value=move || choice().unwrap_or_default().to_string()
+                                                    on:input=move |ev| {
+                                                        set_choice(event_target_value(&ev).parse().ok());
+                                                        set_hash(Ok(None));
+                                                    }
+                                                />
+                                                <span class="w-8 text-center font-medium">
+                                                    {move || {
+                                                        choice()
+                                                            .and_then(|index| K::CHOICES.get(index))
+                                                            .map(|(_, label)| *label)
+                                                    }}
+                                                </span>
+                                            </div>
+                                        }
+                                            .into_any()
+                                    } else {
+                                        view! {
+                                            <select
+                                                id="commit-choice"
+                                                class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:text-white"
+                                                required
+                                                on:change=move |ev| {
+                                                    set_choice(event_target_value(&ev).parse().ok());
+                                                    set_hash(Ok(None));
+                                                }
+                                            >
+                                                <option value="">"Select your vote"</option>
+                                                {K::CHOICES
+                                                    .iter()
+                                                    .enumerate()
+                                                    .map(|(index, (_, label))| {
+                                                        view! {
+                                                            <option
+                                                                value=index.to_string()
+                                                                selected=move || choice() == Some(index)
+                                                            >
+                                                                {*label}
+                                                            </option>
+                                                        }
+                                                    })
+                                                    .collect::<Vec<_>>()}
+                                            </select>
+                                        }
+                                            .into_any()
+                                    }}
+                                </div>
+                                <div class="mb-5">
+                                    <label
+                                        for="commit-salt"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        Salt
+                                    </label>
+                                    <div class="flex gap-2">
+                                        <input
+                                            type="text"
+                                            id="commit-salt"
+                                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg block w-full p-2.5 font-mono dark:bg-gray-700 dark:border-gray-600 dark:text-white"
+                                            readonly
+                                            prop:value=move || salt().unwrap_or_default()
+                                        />
+                                        <button
+                                            type="button"
+                                            class="whitespace-nowrap text-sm px-3 rounded-lg border border-gray-300 dark:border-gray-600 dark:text-white"
+                                            on:click=move |_| set_salt(generate_salt())
+                                        >
+                                            New salt
+                                        </button>
+                                    </div>
+                                </div>
+                                <div class="mb-5">
+                                    <label
+                                        for="commit-password"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        Password to encrypt the saved vote
+                                    </label>
+                                    <input
+                                        type="password"
+                                        id="commit-password"
+                                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                        required
+                                        prop:value=move || password()
+                                        on:input=move |ev| {
+                                            set_password(event_target_value(&ev));
+                                            set_hash(Ok(None));
+                                        }
+                                    />
+                                </div>
+                                <div class="mb-5">
+                                    <label
+                                        for="commit-confirm-password"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        Confirm password
+                                    </label>
+                                    <input
+                                        type="password"
+                                        id="commit-confirm-password"
+                                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                        required
+                                        prop:value=move || confirm_password()
+                                        on:input=move |ev| {
+                                            set_confirm_password(event_target_value(&ev));
+                                            set_hash(Ok(None));
+                                        }
+                                    />
+                                </div>
+                                <button
+                                    type="submit"
+                                    id="commit-vote-submit"
+                                    class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800 disabled:opacity-50"
+                                    disabled=saving
+                                >
+
+                                    {move || if saving() { "Saving vote..." } else { "Submit" }}
+                                </button>
+                            </form>
+                            <br />
+                            <AppErrorBoundary>{move || hash().map(|_| ())}</AppErrorBoundary>
+                        </div>
+                    }.into_any()
+                }
+                View::Success => {
+                    view! {
+                        <div>
+                            <GameTransaction tx=K::commit_vote(
+                                &id,
+                                hash().ok().flatten().unwrap_or_default(),
+                            ) />
+
+                        </div>
+                    }.into_any()
+                }
+            }
+    };
+
+    view! { <div>{move || render_view()}</div> }
+}
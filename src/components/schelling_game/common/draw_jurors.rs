@@ -1,28 +1,32 @@
-use crate::components::schelling_game::profile_validation::change_period::ChangePeriod;
-use crate::components::schelling_game::profile_validation::draw_jurors_sign_in::SignTransaction;
-use crate::components::schelling_game::profile_validation::rpc::drawing_period_end::DrawingEndBlock;
-use crate::components::schelling_game::profile_validation::storage::get_period::GetPeriod;
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::change_period::ChangePeriod;
+use crate::components::schelling_game::common::drawing_period_end::DrawingEndBlock;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::game_transaction::GameTransaction;
+use crate::components::schelling_game::common::get_period::GetPeriod;
 use crate::services::common_imp::View;
-use crate::services::error::ErrorString;
+use crate::services::error::AppError;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 
 #[component]
-pub fn DrawJurors(profile_user_account: String) -> impl IntoView {
-    // gloo::console::log!(profile_user_account());
+pub fn DrawJurors<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
     let (current_view, set_current_view) = signal(View::Form);
-    let (iterations, set_iterations) = signal::<Result<u64, ErrorString>>(Ok(0));
+    let (iterations, set_iterations) = signal::<Result<u64, AppError>>(Ok(0));
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
-
-        set_current_view(View::Success);
+        if iterations().is_ok() {
+            set_current_view(View::Success);
+        }
     };
 
     let iteration_function = move |value: String| {
-        let iteration_value = value.parse::<u64>().expect("Invalid input");
-        gloo::console::log!(iteration_value);
+        let iteration_value = value
+            .parse::<u64>()
+            .map_err(|e| AppError::Validation(format!("Invalid iterations: {e}")));
+        gloo::console::log!(format!("{:?}", iteration_value));
 
-        set_iterations(Ok(iteration_value));
+        set_iterations(iteration_value);
     };
 
     let render_view = move || {
@@ -31,9 +35,9 @@ pub fn DrawJurors(profile_user_account: String) -> impl IntoView {
             view! {
                 <div class="max-w-5xl mx-auto max-md:mx-10">
                     <div class="text-gray-900 dark:text-white">
-                        <GetPeriod profile_user_account=profile_user_account.clone() />
-                        <DrawingEndBlock profile_user_account=profile_user_account.clone() />
-                        <ChangePeriod profile_user_account=profile_user_account.clone() />
+                        <GetPeriod<K> id=id.clone() />
+                        <DrawingEndBlock<K> id=id.clone() />
+                        <ChangePeriod<K> id=id.clone() />
                     </div>
 
                     <form id="draw-juror-submit-from" on:submit=submit_click>
@@ -61,16 +65,15 @@ pub fn DrawJurors(profile_user_account: String) -> impl IntoView {
                             Draw Jurors
                         </button>
                     </form>
+                    <br />
+                    <AppErrorBoundary>{move || iterations().map(|_| ())}</AppErrorBoundary>
                 </div>
             }.into_any()
         }
         View::Success => {
             view! {
                 <div>
-                    <SignTransaction
-                        iterations=iterations().unwrap()
-                        profile_user_account=profile_user_account.clone()
-                    />
+                    <GameTransaction tx=K::draw_jurors(&id, iterations().unwrap_or_default()) />
 
                 </div>
             }.into_any()
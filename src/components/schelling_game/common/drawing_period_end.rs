@@ -0,0 +1,75 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::services::chain_client::ChainClient;
+use crate::services::chain_head::ChainHead;
+use crate::services::error::AppError;
+use crate::services::shivarthu_rpc::DrawingPeriodStatus;
+use icondata;
+use leptos::prelude::*;
+use leptos_icons::*;
+
+async fn load_data<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: K::Id,
+) -> Result<DrawingPeriodStatus, AppError> {
+    let rpc = chain_client.shivarthu_rpc().await?;
+    rpc.drawing_period_end(&K::game_key(&id)).await
+}
+
+#[component]
+pub fn DrawingEndBlock<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
+    let (drawing_period, set_drawing_period) =
+        signal::<Option<Result<DrawingPeriodStatus, AppError>>>(None);
+
+    let action: Action<K::Id, ()> = Action::new_unsync(move |id: &K::Id| {
+        let id = id.clone();
+        async move { set_drawing_period(Some(load_data::<K>(chain_client, id).await)) }
+    });
+
+    // Re-query once on mount and then whenever a new block is finalized.
+    let current_block = expect_context::<ChainHead>().current_block();
+    Effect::new({
+        let id = id.clone();
+        move |_| {
+            current_block.track();
+            action.dispatch(id.clone());
+        }
+    });
+
+    let retry = move |_: ()| {
+        action.dispatch(id.clone());
+    };
+
+    view! {
+        <AppErrorBoundary on_retry=retry>
+            {move || {
+                drawing_period()
+                    .map(|result| {
+                        result
+                            .map(|status| {
+                                view! {
+                                    <div>
+                                        {"Drawing Period ends: "}
+                                        <span id="end-period-time">{status.period_ended}</span>
+                                    </div>
+                                }
+                                    .into_any()
+                            })
+                    })
+                    .unwrap_or_else(|| {
+                        Ok(
+                            view! {
+                                <div>
+                                    {"Drawing Period ends: "} <span id="end-period-time">
+                                        <Icon icon=icondata::ImSpinner6 style="color: green" />
+                                    </span>
+                                </div>
+                            }
+                                .into_any(),
+                        )
+                    })
+            }}
+        </AppErrorBoundary>
+    }
+}
@@ -0,0 +1,80 @@
+use crate::services::common_services::polkadot;
+use crate::services::error::AppError;
+use crate::services::shivarthu_rpc::GameKey;
+use polkadot::runtime_types::pallet_sortition_sum_game::types::SumTreeName;
+use std::fmt::{Debug, Display};
+use std::num::ParseIntError;
+use std::str::FromStr;
+use subxt::dynamic::Value;
+use subxt::tx::Payload;
+
+/// What sets one Schelling game apart from another.
+///
+/// The generic components in this module only talk to the chain through
+/// this trait, so adding a game means adding one implementation in
+/// [`super::games`].
+pub trait SchellingGameKind: 'static {
+    /// Identifies one game: an account address or a numeric id.
+    type Id: Clone + Debug + Display + PartialEq + Send + Sync + 'static;
+    /// Value a juror reveals in the vote period.
+    type Choice: Clone
+        + Debug
+        + Default
+        + Display
+        + FromStr<Err = ParseIntError>
+        + Send
+        + Sync
+        + 'static;
+
+    /// Pallet that stores the game's `ValidationBlock`.
+    const PALLET: &'static str;
+
+    /// Name of the game shown to users.
+    const NAME: &'static str;
+
+    /// Route of the game page, followed by `/<id>`.
+    const GAME_PAGE: &'static str;
+
+    /// Choices offered when committing a vote, with their labels.
+    const CHOICES: &'static [(Self::Choice, &'static str)];
+
+    /// Jurors score the game instead of voting yes or no. Their commits are
+    /// then kept in `ScoreVoteCommits` rather than `VoteCommits`.
+    const SCORE_GAME: bool = false;
+
+    /// Key used for the game's custom RPC methods.
+    fn game_key(id: &Self::Id) -> GameKey;
+
+    /// The id encoded as a storage key for [`Self::PALLET`].
+    fn id_value(id: &Self::Id) -> Result<Value, AppError>;
+
+    /// Sortition tree of the game started at `block_number`.
+    fn sum_tree_name(id: &Self::Id, block_number: u32) -> Result<SumTreeName, AppError>;
+
+    fn apply_jurors(id: &Self::Id, stake: u128) -> Result<Box<dyn Payload>, AppError>;
+
+    fn draw_jurors(id: &Self::Id, iterations: u64) -> Result<Box<dyn Payload>, AppError>;
+
+    fn pass_period(id: &Self::Id) -> Result<Box<dyn Payload>, AppError>;
+
+    fn commit_vote(id: &Self::Id, vote_commit: [u8; 32]) -> Result<Box<dyn Payload>, AppError>;
+
+    fn reveal_vote(
+        id: &Self::Id,
+        choice: Self::Choice,
+        salt: Vec<u8>,
+    ) -> Result<Box<dyn Payload>, AppError>;
+
+    fn unstaking(id: &Self::Id) -> Result<Box<dyn Payload>, AppError>;
+
+    /// Claims the caller's juror incentives once the game is executed.
+    fn get_incentives(id: &Self::Id) -> Result<Box<dyn Payload>, AppError>;
+
+    /// Starts the staking period. Games that open with an evidence period
+    /// instead have no such extrinsic.
+    fn apply_staking_period(_id: &Self::Id) -> Result<Box<dyn Payload>, AppError> {
+        Err(AppError::Validation(
+            "This game has no staking period to start".to_string(),
+        ))
+    }
+}
@@ -0,0 +1,296 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::get_period_fn::{
+    current_period, current_sum_tree_name,
+};
+use crate::services::chain_client::ChainClient;
+use crate::services::chain_head::ChainHead;
+use crate::services::common_services::polkadot;
+use crate::services::error::AppError;
+use jsonrpsee_core::{client::ClientT, rpc_params};
+use leptos::prelude::*;
+use polkadot::runtime_types::pallet_schelling_game_shared::types::Period;
+use polkadot::runtime_types::pallet_sortition_sum_game::types::SumTreeName;
+use subxt::utils::H256;
+
+/// Periods in the order a game goes through them.
+const PERIODS: [Period; 7] = [
+    Period::Evidence,
+    Period::Staking,
+    Period::Drawing,
+    Period::Commit,
+    Period::Vote,
+    Period::Appeal,
+    Period::Execution,
+];
+
+fn position(period: &Period) -> usize {
+    PERIODS.iter().position(|p| p == period).unwrap_or_default()
+}
+
+/// One period of the game's current round.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimelineStep {
+    pub period: Period,
+    /// Block at which the period started, when it has started and is known.
+    pub start: Option<u32>,
+    /// Block from which `pass_period` may leave the period.
+    pub end: Option<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timeline {
+    pub current: Period,
+    pub steps: Vec<TimelineStep>,
+    /// Drawing can be left once all jurors are drawn, whatever the block.
+    pub drawing_ended: bool,
+}
+
+/// Period of round `key` as it was at `block`. `None` when the node no
+/// longer keeps the state of that block.
+async fn period_at(chain_client: ChainClient, key: &SumTreeName, block: u32) -> Option<Period> {
+    let read = async {
+        let hash: Option<H256> = chain_client
+            .rpc()
+            .await?
+            .request("chain_getBlockHash", rpc_params![block])
+            .await
+            .map_err(|e| AppError::Rpc(e.to_string()))?;
+        let Some(hash) = hash else {
+            return Ok(None);
+        };
+        let period_storage = polkadot::storage()
+            .schelling_game_shared()
+            .period_name(key.clone());
+        Ok::<_, AppError>(
+            chain_client
+                .online()
+                .await?
+                .storage()
+                .at(hash)
+                .fetch(&period_storage)
+                .await?,
+        )
+    };
+    read.await.ok().flatten()
+}
+
+/// First block in `from..=to` at which the round had reached `period`,
+/// found by bisecting historical reads of `PeriodName`.
+async fn period_start(
+    chain_client: ChainClient,
+    key: &SumTreeName,
+    period: &Period,
+    mut from: u32,
+    mut to: u32,
+) -> Option<u32> {
+    while from < to {
+        let middle = from + (to - from) / 2;
+        if position(&period_at(chain_client, key, middle).await?) >= position(period) {
+            to = middle;
+        } else {
+            from = middle + 1;
+        }
+    }
+    Some(to)
+}
+
+async fn load_data<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: K::Id,
+) -> Result<Option<Timeline>, AppError> {
+    let Some(key) = current_sum_tree_name::<K>(chain_client, &id).await? else {
+        return Ok(None);
+    };
+    let client = chain_client.online().await?;
+    let storage = client.storage().at_latest().await?;
+    let games = polkadot::storage().schelling_game_shared();
+    let Some(current) = storage.fetch(&games.period_name(key.clone())).await? else {
+        return Ok(None);
+    };
+    let latest = client.blocks().at_latest().await?.number();
+
+    // Start times default to 0 for periods the round has not reached.
+    let recorded = |start: u32| (start > 0).then_some(start);
+    let evidence = recorded(
+        storage
+            .fetch_or_default(&games.evidence_start_time(key.clone()))
+            .await?,
+    );
+    let staking = recorded(
+        storage
+            .fetch_or_default(&games.staking_start_time(key.clone()))
+            .await?,
+    );
+    let commit = recorded(
+        storage
+            .fetch_or_default(&games.commit_start_time(key.clone()))
+            .await?,
+    );
+    let vote = recorded(
+        storage
+            .fetch_or_default(&games.vote_start_time(key.clone()))
+            .await?,
+    );
+
+    let reached = |period: &Period| position(&current) >= position(period);
+    let drawing = match staking {
+        Some(from) if reached(&Period::Drawing) => {
+            period_start(
+                chain_client,
+                &key,
+                &Period::Drawing,
+                from,
+                commit.unwrap_or(latest),
+            )
+            .await
+        }
+        _ => None,
+    };
+    let appeal = match vote {
+        Some(from) if reached(&Period::Appeal) => {
+            period_start(chain_client, &key, &Period::Appeal, from, latest).await
+        }
+        _ => None,
+    };
+    // Games may go from vote straight to execution, so an appeal start
+    // found here can be the execution start.
+    let execution = match appeal.or(vote) {
+        Some(from) if reached(&Period::Execution) => {
+            period_start(chain_client, &key, &Period::Execution, from, latest).await
+        }
+        _ => None,
+    };
+
+    let rpc = chain_client.shivarthu_rpc().await?;
+    let game_key = K::game_key(&id);
+    let ends = [
+        rpc.evidence_end_block(&game_key).await?,
+        rpc.staking_end_block(&game_key).await?,
+        commit,
+        rpc.commit_end_block(&game_key).await?,
+        rpc.vote_end_block(&game_key).await?,
+        execution,
+        None,
+    ];
+    let starts = [evidence, staking, drawing, commit, vote, appeal, execution];
+    let drawing_ended =
+        current == Period::Drawing && rpc.drawing_period_end(&game_key).await?.period_ended;
+
+    Ok(Some(Timeline {
+        current,
+        steps: PERIODS
+            .into_iter()
+            .zip(starts.into_iter().zip(ends))
+            .map(|(period, (start, end))| TimelineStep { period, start, end })
+            .collect(),
+        drawing_ended,
+    }))
+}
+
+/// Every period of the game's current round with its start and end blocks,
+/// and whether `pass_period` can move the game on now.
+#[component]
+pub fn GameTimeline<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
+    let current_block = expect_context::<ChainHead>().current_block();
+    // The period is read again at every finalized block, and the timeline,
+    // which bisects historical blocks, only when the period has changed.
+    let period = LocalResource::new({
+        let id = id.clone();
+        move || {
+            current_block.track();
+            current_period::<K>(chain_client, id.clone())
+        }
+    });
+    let period = Memo::new(
+        move |previous: Option<&Option<Period>>| match period.get() {
+            Some(Ok(period)) => period,
+            _ => previous.cloned().flatten(),
+        },
+    );
+    let timeline = LocalResource::new(move || {
+        period.track();
+        load_data::<K>(chain_client, id.clone())
+    });
+
+    let transition = move |timeline: &Timeline| {
+        let index = position(&timeline.current);
+        if timeline.current == Period::Execution {
+            return "The game is over".to_string();
+        }
+        let available = match timeline.current {
+            Period::Drawing => Some(timeline.drawing_ended),
+            Period::Appeal => None,
+            _ => timeline.steps[index]
+                .end
+                .zip(current_block.get())
+                .map(|(end, now)| now >= end),
+        };
+        match available {
+            Some(true) => "Pass period is available now".to_string(),
+            Some(false) => "Pass period is not available yet".to_string(),
+            None => "Pass period may be available, try Change Period".to_string(),
+        }
+    };
+
+    let block = |block: Option<u32>| block.map_or("-".to_string(), |block| block.to_string());
+
+    let timeline_view = move || {
+        timeline.get().transpose().map(|timeline| {
+            let Some(timeline) = timeline.flatten() else {
+                return view! { <p>"The game has not started yet"</p> }.into_any();
+            };
+            let current = position(&timeline.current);
+            let rows = timeline
+                .steps
+                .iter()
+                .enumerate()
+                .map(|(index, step)| {
+                    let class = if index == current {
+                        "font-semibold text-blue-700 dark:text-blue-400"
+                    } else if index < current {
+                        "text-gray-500 dark:text-gray-400"
+                    } else {
+                        ""
+                    };
+                    view! {
+                        <tr class=class>
+                            <td class="py-1 pr-4">
+                                {format!("{:?}", step.period)}
+                                {(index == current).then_some(" (current)")}
+                            </td>
+                            <td class="py-1 pr-4">{block(step.start)}</td>
+                            <td class="py-1">{block(step.end)}</td>
+                        </tr>
+                    }
+                })
+                .collect::<Vec<_>>();
+            view! {
+                <div>
+                    <table class="w-full text-left text-sm">
+                        <thead>
+                            <tr>
+                                <th class="py-1 pr-4">Period</th>
+                                <th class="py-1 pr-4">Started at block</th>
+                                <th class="py-1">Ends at block</th>
+                            </tr>
+                        </thead>
+                        <tbody>{rows}</tbody>
+                    </table>
+                    <p class="mt-3">{move || transition(&timeline)}</p>
+                </div>
+            }
+            .into_any()
+        })
+    };
+
+    view! {
+        <div class="max-w-5xl mx-auto max-md:mx-10 my-5 text-gray-900 dark:text-white">
+            <h2 class="text-xl font-semibold mb-3">Timeline</h2>
+            <AppErrorBoundary on_retry=move |_: ()| timeline.refetch()>
+                {move || timeline_view()}
+            </AppErrorBoundary>
+        </div>
+    }
+}
@@ -0,0 +1,12 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::signing::sign_transaction_fn::SignTransactionFn;
+use crate::services::error::AppError;
+use leptos::prelude::*;
+
+/// Signs and submits a game extrinsic, or shows why it could not be built.
+#[component]
+pub fn GameTransaction(tx: Result<Box<dyn subxt::tx::Payload>, AppError>) -> impl IntoView {
+    let sign_transaction = tx.map(|tx| view! { <SignTransactionFn tx=tx /> });
+
+    view! { <AppErrorBoundary>{sign_transaction}</AppErrorBoundary> }
+}
@@ -0,0 +1,408 @@
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::services::common_services::{account_id_from_str, polkadot};
+use crate::services::error::AppError;
+use crate::services::shivarthu_rpc::GameKey;
+use polkadot::runtime_types::pallet_sortition_sum_game::types::SumTreeName;
+use subxt::dynamic::Value;
+use subxt::tx::Payload;
+use subxt::utils::AccountId32;
+use subxt::Metadata;
+
+/// Validation of a citizen's profile, keyed by the citizen's address.
+pub struct ProfileValidationGame;
+
+impl SchellingGameKind for ProfileValidationGame {
+    type Id = String;
+    type Choice = u128;
+
+    const PALLET: &'static str = "ProfileValidation";
+
+    const NAME: &'static str = "Profile validation";
+
+    const GAME_PAGE: &'static str = "/profile-validation/schelling-game";
+
+    const CHOICES: &'static [(u128, &'static str)] = &[(1, "Yes"), (0, "No")];
+
+    fn game_key(id: &String) -> GameKey {
+        GameKey::ProfileValidation {
+            profile_user_account: id.clone(),
+        }
+    }
+
+    fn id_value(id: &String) -> Result<Value, AppError> {
+        Ok(account_value(&account_id_from_str(id)?))
+    }
+
+    fn sum_tree_name(id: &String, block_number: u32) -> Result<SumTreeName, AppError> {
+        Ok(SumTreeName::ProfileValidation {
+            citizen_address: account_id_from_str(id)?,
+            block_number,
+        })
+    }
+
+    fn apply_jurors(id: &String, stake: u128) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .profile_validation()
+                .apply_jurors(account_id_from_str(id)?, stake),
+        ))
+    }
+
+    fn draw_jurors(id: &String, iterations: u64) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .profile_validation()
+                .draw_jurors(account_id_from_str(id)?, iterations),
+        ))
+    }
+
+    fn pass_period(id: &String) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .profile_validation()
+                .pass_period(account_id_from_str(id)?),
+        ))
+    }
+
+    fn commit_vote(id: &String, vote_commit: [u8; 32]) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .profile_validation()
+                .commit_vote(account_id_from_str(id)?, vote_commit),
+        ))
+    }
+
+    fn reveal_vote(id: &String, choice: u128, salt: Vec<u8>) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(polkadot::tx().profile_validation().reveal_vote(
+            account_id_from_str(id)?,
+            choice,
+            salt,
+        )))
+    }
+
+    fn unstaking(id: &String) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .profile_validation()
+                .unstaking(account_id_from_str(id)?),
+        ))
+    }
+
+    fn get_incentives(id: &String) -> Result<Box<dyn Payload>, AppError> {
+        incentives_call::<Self>(id)
+    }
+}
+
+/// Scoring of a user's positive externality posts, keyed by the user's
+/// address.
+pub struct PositiveExternalityGame;
+
+impl SchellingGameKind for PositiveExternalityGame {
+    type Id = String;
+    type Choice = i64;
+
+    const PALLET: &'static str = "PositiveExternality";
+
+    const NAME: &'static str = "Positive externality";
+
+    const GAME_PAGE: &'static str = "/positive-externality/schelling-game";
+
+    /// Scores from 1 to 5. `pallet_positive_externality::reveal_vote` fails
+    /// with `Error::ChoiceOutOfRange` for anything else.
+    ///
+    /// A commit is only a hash, so a score of 0 sent through the old number
+    /// input was accepted at commit time. Such a vote can still be filled in
+    /// from the vote vault, but the reveal page reports the range error instead
+    /// of submitting a reveal the pallet would refuse.
+    const CHOICES: &'static [(i64, &'static str)] =
+        &[(1, "1"), (2, "2"), (3, "3"), (4, "4"), (5, "5")];
+
+    const SCORE_GAME: bool = true;
+
+    fn game_key(id: &String) -> GameKey {
+        GameKey::PositiveExternality {
+            user_to_calculate: id.clone(),
+        }
+    }
+
+    fn id_value(id: &String) -> Result<Value, AppError> {
+        Ok(account_value(&account_id_from_str(id)?))
+    }
+
+    fn sum_tree_name(id: &String, block_number: u32) -> Result<SumTreeName, AppError> {
+        Ok(SumTreeName::PositiveExternality {
+            user_address: account_id_from_str(id)?,
+            block_number,
+        })
+    }
+
+    fn apply_jurors(id: &String, stake: u128) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .positive_externality()
+                .apply_jurors(account_id_from_str(id)?, stake),
+        ))
+    }
+
+    fn draw_jurors(id: &String, iterations: u64) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .positive_externality()
+                .draw_jurors(account_id_from_str(id)?, iterations),
+        ))
+    }
+
+    fn pass_period(id: &String) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .positive_externality()
+                .pass_period(account_id_from_str(id)?),
+        ))
+    }
+
+    fn commit_vote(id: &String, vote_commit: [u8; 32]) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .positive_externality()
+                .commit_vote(account_id_from_str(id)?, vote_commit),
+        ))
+    }
+
+    fn reveal_vote(id: &String, choice: i64, salt: Vec<u8>) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(polkadot::tx().positive_externality().reveal_vote(
+            account_id_from_str(id)?,
+            choice,
+            salt,
+        )))
+    }
+
+    fn unstaking(id: &String) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .positive_externality()
+                .unstaking(account_id_from_str(id)?),
+        ))
+    }
+
+    fn get_incentives(id: &String) -> Result<Box<dyn Payload>, AppError> {
+        incentives_call::<Self>(id)
+    }
+
+    fn apply_staking_period(id: &String) -> Result<Box<dyn Payload>, AppError> {
+        Ok(Box::new(
+            polkadot::tx()
+                .positive_externality()
+                .apply_staking_period(account_id_from_str(id)?),
+        ))
+    }
+}
+
+/// Tipping of a project, keyed by project id.
+///
+/// The `ProjectTips` pallet is not part of the bundled metadata, so its
+/// extrinsics are built dynamically and checked against the node's
+/// metadata at submission time.
+pub struct ProjectTipsGame;
+
+impl SchellingGameKind for ProjectTipsGame {
+    type Id = u64;
+    type Choice = u128;
+
+    const PALLET: &'static str = "ProjectTips";
+
+    const NAME: &'static str = "Project tips";
+
+    const GAME_PAGE: &'static str = "/project-tips/schelling-game";
+
+    const CHOICES: &'static [(u128, &'static str)] = &[(1, "Yes"), (0, "No")];
+
+    fn game_key(id: &u64) -> GameKey {
+        GameKey::ProjectTips { project_id: *id }
+    }
+
+    fn id_value(id: &u64) -> Result<Value, AppError> {
+        Ok(Value::u128(*id as u128))
+    }
+
+    fn sum_tree_name(id: &u64, block_number: u32) -> Result<SumTreeName, AppError> {
+        Ok(SumTreeName::ProjectTips {
+            project_id: *id,
+            block_number,
+        })
+    }
+
+    fn apply_jurors(id: &u64, stake: u128) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("apply_jurors", id, vec![Value::u128(stake)])
+    }
+
+    fn draw_jurors(id: &u64, iterations: u64) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("draw_jurors", id, vec![Value::u128(iterations as u128)])
+    }
+
+    fn pass_period(id: &u64) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("pass_period", id, vec![])
+    }
+
+    fn commit_vote(id: &u64, vote_commit: [u8; 32]) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("commit_vote", id, vec![Value::from_bytes(vote_commit)])
+    }
+
+    fn reveal_vote(id: &u64, choice: u128, salt: Vec<u8>) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>(
+            "reveal_vote",
+            id,
+            vec![Value::u128(choice), Value::from_bytes(salt)],
+        )
+    }
+
+    fn unstaking(id: &u64) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("unstaking", id, vec![])
+    }
+
+    fn get_incentives(id: &u64) -> Result<Box<dyn Payload>, AppError> {
+        incentives_call::<Self>(id)
+    }
+
+    fn apply_staking_period(id: &u64) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("apply_staking_period", id, vec![])
+    }
+}
+
+/// Funding of a department's required fund, keyed by the fund id.
+///
+/// Like [`ProjectTipsGame`], the `DepartmentFunding` pallet is missing from
+/// the bundled metadata and is called dynamically.
+pub struct DepartmentFundingGame;
+
+impl SchellingGameKind for DepartmentFundingGame {
+    type Id = u64;
+    type Choice = u128;
+
+    const PALLET: &'static str = "DepartmentFunding";
+
+    const NAME: &'static str = "Department funding";
+
+    const GAME_PAGE: &'static str = "/department-funding/schelling-game";
+
+    const CHOICES: &'static [(u128, &'static str)] = &[(1, "Yes"), (0, "No")];
+
+    fn game_key(id: &u64) -> GameKey {
+        GameKey::DepartmentFunding {
+            department_required_fund_id: *id,
+        }
+    }
+
+    fn id_value(id: &u64) -> Result<Value, AppError> {
+        Ok(Value::u128(*id as u128))
+    }
+
+    fn sum_tree_name(id: &u64, block_number: u32) -> Result<SumTreeName, AppError> {
+        Ok(SumTreeName::DepartmentRequiredFund {
+            department_required_fund_id: *id,
+            block_number,
+        })
+    }
+
+    fn apply_jurors(id: &u64, stake: u128) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("apply_jurors", id, vec![Value::u128(stake)])
+    }
+
+    fn draw_jurors(id: &u64, iterations: u64) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("draw_jurors", id, vec![Value::u128(iterations as u128)])
+    }
+
+    fn pass_period(id: &u64) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("pass_period", id, vec![])
+    }
+
+    fn commit_vote(id: &u64, vote_commit: [u8; 32]) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("commit_vote", id, vec![Value::from_bytes(vote_commit)])
+    }
+
+    fn reveal_vote(id: &u64, choice: u128, salt: Vec<u8>) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>(
+            "reveal_vote",
+            id,
+            vec![Value::u128(choice), Value::from_bytes(salt)],
+        )
+    }
+
+    fn unstaking(id: &u64) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("unstaking", id, vec![])
+    }
+
+    fn get_incentives(id: &u64) -> Result<Box<dyn Payload>, AppError> {
+        incentives_call::<Self>(id)
+    }
+
+    fn apply_staking_period(id: &u64) -> Result<Box<dyn Payload>, AppError> {
+        dynamic_call::<Self>("apply_staking_period", id, vec![])
+    }
+}
+
+/// Builds `K::PALLET::call(id, args..)` without static metadata.
+pub(crate) fn dynamic_call<K: SchellingGameKind>(
+    call: &'static str,
+    id: &K::Id,
+    args: Vec<Value>,
+) -> Result<Box<dyn Payload>, AppError> {
+    let mut fields = vec![K::id_value(id)?];
+    fields.extend(args);
+    Ok(Box::new(subxt::dynamic::tx(K::PALLET, call, fields)))
+}
+
+/// Claims juror incentives under whichever name the node's pallet uses.
+///
+/// The bundled metadata only has `get_incentives`, while the runtime the
+/// per-game pages were written against calls it `add_incentive_count`, so
+/// the name is looked up in the node's metadata when the call is encoded.
+pub(crate) fn incentives_call<K: SchellingGameKind>(
+    id: &K::Id,
+) -> Result<Box<dyn Payload>, AppError> {
+    Ok(Box::new(IncentivesCall {
+        pallet: K::PALLET,
+        fields: vec![K::id_value(id)?],
+    }))
+}
+
+struct IncentivesCall {
+    pallet: &'static str,
+    fields: Vec<Value>,
+}
+
+impl Payload for IncentivesCall {
+    fn encode_call_data_to(
+        &self,
+        metadata: &Metadata,
+        out: &mut Vec<u8>,
+    ) -> Result<(), subxt::ext::subxt_core::Error> {
+        let call = metadata
+            .pallet_by_name(self.pallet)
+            .filter(|pallet| pallet.call_variant_by_name("add_incentive_count").is_some())
+            .map_or("get_incentives", |_| "add_incentive_count");
+        subxt::dynamic::tx(self.pallet, call, self.fields.clone())
+            .encode_call_data_to(metadata, out)
+    }
+}
+
+fn account_value(account_id32: &AccountId32) -> Value {
+    Value::from_bytes(account_id32.0)
+}
+
+/// `pallet_support::Content::IPFS(cid)` as a dynamic value.
+pub(crate) fn ipfs_content_value(cid: &str) -> Value {
+    Value::unnamed_variant("IPFS", [Value::from_bytes(cid.as_bytes())])
+}
+
+/// `TippingName` variant of the tipping and funding pallets.
+pub(crate) fn tipping_name_value(tip_name: &str) -> Result<Value, AppError> {
+    match tip_name {
+        "SmallTipper" | "BigTipper" | "SmallSpender" | "MediumSpender" | "BigSpender" => {
+            Ok(Value::unnamed_variant(tip_name, []))
+        }
+        _ => Err(AppError::Validation(format!(
+            "Unknown tipper: {:?}",
+            tip_name
+        ))),
+    }
+}
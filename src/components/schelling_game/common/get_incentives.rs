@@ -1,11 +1,11 @@
-use crate::components::schelling_game::project_tips::get_incentives_sign_in::SignTransaction;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::game_transaction::GameTransaction;
 use crate::services::common_imp::View;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 
 #[component]
-pub fn GetIncentives(project_id: u64) -> impl IntoView {
-    // gloo::console::log!(project_id());
+pub fn GetIncentives<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
     let (current_view, set_current_view) = signal(View::Form);
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
@@ -33,12 +33,12 @@ pub fn GetIncentives(project_id: u64) -> impl IntoView {
             View::Success => {
                 view! {
                     <div>
-                        <SignTransaction project_id=project_id.clone() />
+                        <GameTransaction tx=K::get_incentives(&id) />
 
                     </div>
                 }.into_any()
             }
-        
+
         }
     };
     view! { <div>{move || render_view()}</div> }
@@ -0,0 +1,25 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::get_period_fn::get_period_fn;
+use leptos::prelude::*;
+
+#[component]
+pub fn GetPeriod<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
+    let period = get_period_fn::<K>(id);
+    let period_value = move || {
+        period
+            .get()
+            .transpose()
+            .map(|period| match period.flatten() {
+                Some(value) => format!("Period name: {:?}", value),
+                None => format!(""),
+            })
+    };
+    view! {
+        <div>
+            <AppErrorBoundary on_retry=move |_: ()| period.refetch()>
+                <code>{move || period_value()}</code>
+            </AppErrorBoundary>
+        </div>
+    }
+}
@@ -0,0 +1,70 @@
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::services::chain_client::ChainClient;
+use crate::services::common_services::polkadot;
+use crate::services::error::AppError;
+use leptos::prelude::*;
+use polkadot::runtime_types::pallet_schelling_game_shared::types::Period;
+use polkadot::runtime_types::pallet_sortition_sum_game::types::SumTreeName;
+
+/// Block at which the current round of the game started, if any.
+pub async fn validation_block<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: &K::Id,
+) -> Result<Option<u32>, AppError> {
+    let client = chain_client.online().await?;
+
+    let validation_block_storage =
+        subxt::dynamic::storage(K::PALLET, "ValidationBlock", vec![K::id_value(id)?]);
+
+    let validation_block = client
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&validation_block_storage)
+        .await?;
+
+    validation_block
+        .map(|value| value.as_type::<u32>())
+        .transpose()
+        .map_err(|e| AppError::Decode(e.to_string()))
+}
+
+/// Sortition tree of the game's current round, if one has started.
+pub async fn current_sum_tree_name<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: &K::Id,
+) -> Result<Option<SumTreeName>, AppError> {
+    validation_block::<K>(chain_client, id)
+        .await?
+        .map(|block_number| K::sum_tree_name(id, block_number))
+        .transpose()
+}
+
+/// Period of the game's current round, if one has started.
+pub async fn current_period<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: K::Id,
+) -> Result<Option<Period>, AppError> {
+    let Some(key) = current_sum_tree_name::<K>(chain_client, &id).await? else {
+        return Ok(None);
+    };
+
+    let client = chain_client.online().await?;
+    let period_storage = polkadot::storage().schelling_game_shared().period_name(key);
+    Ok(client
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&period_storage)
+        .await?)
+}
+
+/// Loads the current period of the game; `refetch` on the returned resource
+/// retries after an error.
+pub fn get_period_fn<K: SchellingGameKind>(
+    id: K::Id,
+) -> LocalResource<Result<Option<Period>, AppError>> {
+    let chain_client = expect_context::<ChainClient>();
+
+    LocalResource::new(move || current_period::<K>(chain_client, id.clone()))
+}
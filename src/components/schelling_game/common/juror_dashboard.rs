@@ -0,0 +1,343 @@
+use crate::components::common::block_countdown::BlockCountdown;
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
+use crate::components::navigation::nav::Nav;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::games::{
+    DepartmentFundingGame, PositiveExternalityGame, ProfileValidationGame, ProjectTipsGame,
+};
+use crate::components::schelling_game::common::juror_stake_fn::round_stake;
+use crate::components::schelling_game::common::vote_commit_fn::round_commit;
+use crate::services::chain_client::ChainClient;
+use crate::services::common_services::{account_id_from_str, polkadot};
+use crate::services::error::AppError;
+use futures::StreamExt;
+use leptos::prelude::*;
+use polkadot::runtime_types::pallet_schelling_game_shared::types::Period;
+use polkadot::runtime_types::pallet_sortition_sum_game::types::SumTreeName;
+use reactive_stores::Store;
+use subxt::ext::codec::Decode;
+use subxt::utils::AccountId32;
+
+/// Where a juror stands in one game.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JurorTask {
+    Applied,
+    Drawn,
+    Commit,
+    Reveal,
+    Voted,
+    ClaimIncentives,
+}
+
+impl JurorTask {
+    fn label(&self) -> &'static str {
+        match self {
+            JurorTask::Applied => "Applied as juror",
+            JurorTask::Drawn => "Drawn as juror",
+            JurorTask::Commit => "Commit your vote",
+            JurorTask::Reveal => "Reveal your vote",
+            JurorTask::Voted => "Vote revealed",
+            JurorTask::ClaimIncentives => "Incentives to claim",
+        }
+    }
+
+    fn action(&self) -> &'static str {
+        match self {
+            JurorTask::Commit => "Commit vote",
+            JurorTask::Reveal => "Reveal vote",
+            JurorTask::ClaimIncentives => "Claim incentives",
+            _ => "Open game",
+        }
+    }
+}
+
+/// One game the account takes part in as a juror.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DashboardEntry {
+    pub game: &'static str,
+    pub id: String,
+    pub page: String,
+    pub period: Period,
+    pub task: JurorTask,
+    /// Block at which the current period ends, for the periods that have one.
+    pub deadline: Option<u32>,
+}
+
+/// A game whose juror status could not be loaded, shown as its own row so
+/// the other games still appear.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailedEntry {
+    pub game: &'static str,
+    pub id: String,
+    pub page: String,
+    pub error: AppError,
+}
+
+/// Length of the `twox128(pallet) ++ twox128(storage)` prefix of a storage key.
+const STORAGE_PREFIX_LEN: usize = 16 + 16;
+
+/// Length of the hash `Blake2_128Concat` puts before the encoded map key.
+const BLAKE2_128_CONCAT_HASH_LEN: usize = 16;
+
+/// Round `key` with its block number cleared, to tell rounds of the same
+/// game apart from other games.
+fn game_of(key: &SumTreeName) -> (SumTreeName, u32) {
+    let mut game = key.clone();
+    let block = match &mut game {
+        SumTreeName::ProfileValidation { block_number, .. }
+        | SumTreeName::PositiveExternality { block_number, .. }
+        | SumTreeName::DepartmentRequiredFund { block_number, .. }
+        | SumTreeName::ProjectTips { block_number, .. } => std::mem::take(block_number),
+    };
+    (game, block)
+}
+
+/// Latest round of every game that has a period.
+async fn latest_rounds(chain_client: ChainClient) -> Result<Vec<(SumTreeName, Period)>, AppError> {
+    let storage = chain_client.online().await?.storage().at_latest().await?;
+    let mut pairs = storage
+        .iter(
+            polkadot::storage()
+                .schelling_game_shared()
+                .period_name_iter(),
+        )
+        .await?;
+
+    let mut rounds: Vec<(SumTreeName, u32, SumTreeName, Period)> = Vec::new();
+    while let Some(pair) = pairs.next().await {
+        let pair = pair?;
+        let encoded_key = pair
+            .key_bytes
+            .get(STORAGE_PREFIX_LEN + BLAKE2_128_CONCAT_HASH_LEN..)
+            .unwrap_or_default();
+        let key = SumTreeName::decode(&mut &encoded_key[..])
+            .map_err(|e| AppError::Decode(e.to_string()))?;
+        let (game, block) = game_of(&key);
+        match rounds.iter_mut().find(|(known, ..)| *known == game) {
+            Some(round) if round.1 < block => *round = (game, block, key, pair.value),
+            Some(_) => {}
+            None => rounds.push((game, block, key, pair.value)),
+        }
+    }
+    Ok(rounds
+        .into_iter()
+        .map(|(_, _, key, period)| (key, period))
+        .collect())
+}
+
+async fn game_entry<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: K::Id,
+    key: SumTreeName,
+    period: Period,
+    account_id: AccountId32,
+) -> Result<Option<DashboardEntry>, AppError> {
+    let storage = chain_client.online().await?.storage().at_latest().await?;
+    let games = polkadot::storage().schelling_game_shared();
+    let drawn = storage
+        .fetch_or_default(&games.drawn_jurors(key.clone()))
+        .await?
+        .iter()
+        .any(|(juror, _)| *juror == account_id);
+
+    let rpc = chain_client.shivarthu_rpc().await?;
+    let game_key = K::game_key(&id);
+    let task = if drawn {
+        let revealed = match period {
+            Period::Commit | Period::Vote | Period::Execution => {
+                round_commit::<K>(chain_client, key.clone(), account_id.clone())
+                    .await?
+                    .map(|commit| commit.revealed)
+            }
+            _ => None,
+        };
+        match (period.clone(), revealed) {
+            (Period::Commit, None) => JurorTask::Commit,
+            (Period::Vote, Some(false)) => JurorTask::Reveal,
+            (Period::Execution, Some(true)) => {
+                let claimed = storage
+                    .fetch_or_default(&games.jurors_incentive_distributed_accounts(key))
+                    .await?
+                    .contains(&account_id);
+                if claimed {
+                    return Ok(None);
+                }
+                JurorTask::ClaimIncentives
+            }
+            (_, Some(true)) => JurorTask::Voted,
+            _ => JurorTask::Drawn,
+        }
+    } else if round_stake(chain_client, key, &account_id).await? > 0 {
+        JurorTask::Applied
+    } else {
+        return Ok(None);
+    };
+
+    let deadline = match period {
+        Period::Evidence => rpc.evidence_end_block(&game_key).await?,
+        Period::Staking => rpc.staking_end_block(&game_key).await?,
+        Period::Commit => rpc.commit_end_block(&game_key).await?,
+        Period::Vote => rpc.vote_end_block(&game_key).await?,
+        _ => None,
+    };
+
+    Ok(Some(DashboardEntry {
+        game: K::NAME,
+        id: id.to_string(),
+        page: format!("{}/{}", K::GAME_PAGE, id),
+        period,
+        task,
+        deadline,
+    }))
+}
+
+/// Dashboard row of one game; `None` when the account has nothing to do in
+/// it.
+async fn game_row<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: K::Id,
+    key: SumTreeName,
+    period: Period,
+    account_id: AccountId32,
+) -> Option<Result<DashboardEntry, FailedEntry>> {
+    match game_entry::<K>(chain_client, id.clone(), key, period, account_id).await {
+        Ok(entry) => entry.map(Ok),
+        Err(error) => Some(Err(FailedEntry {
+            game: K::NAME,
+            id: id.to_string(),
+            page: format!("{}/{}", K::GAME_PAGE, id),
+            error,
+        })),
+    }
+}
+
+async fn load_data(
+    chain_client: ChainClient,
+    account: String,
+) -> Result<Vec<Result<DashboardEntry, FailedEntry>>, AppError> {
+    if account.is_empty() {
+        return Ok(Vec::new());
+    }
+    let account_id = account_id_from_str(&account)?;
+
+    let mut rows = Vec::new();
+    for (key, period) in latest_rounds(chain_client).await? {
+        let account_id = account_id.clone();
+        let row = match &key {
+            SumTreeName::ProfileValidation {
+                citizen_address, ..
+            } => {
+                let id = citizen_address.to_string();
+                game_row::<ProfileValidationGame>(chain_client, id, key, period, account_id).await
+            }
+            SumTreeName::PositiveExternality { user_address, .. } => {
+                let id = user_address.to_string();
+                game_row::<PositiveExternalityGame>(chain_client, id, key, period, account_id).await
+            }
+            SumTreeName::ProjectTips { project_id, .. } => {
+                let id = *project_id;
+                game_row::<ProjectTipsGame>(chain_client, id, key, period, account_id).await
+            }
+            SumTreeName::DepartmentRequiredFund {
+                department_required_fund_id,
+                ..
+            } => {
+                let id = *department_required_fund_id;
+                game_row::<DepartmentFundingGame>(chain_client, id, key, period, account_id).await
+            }
+        };
+        rows.extend(row);
+    }
+    // Nearest deadline first, games that failed to load last.
+    rows.sort_by_key(|row| match row {
+        Ok(entry) => (false, entry.deadline.unwrap_or(u32::MAX)),
+        Err(_) => (true, u32::MAX),
+    });
+    Ok(rows)
+}
+
+/// Every game the signed-in account has applied to as a juror, nearest
+/// deadline first.
+#[component]
+pub fn JurorDashboard() -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
+    let state = expect_context::<Store<GlobalState>>();
+    let account = state.account_address();
+    let entries = LocalResource::new(move || load_data(chain_client, account.get()));
+
+    let entries_view = move || {
+        entries.get().map(|entries| {
+            entries.map(|entries| {
+                if account.get().is_empty() {
+                    return view! { <p>"Sign in to see your games"</p> }.into_any();
+                }
+                if entries.is_empty() {
+                    return view! { <p>"You have not applied as a juror in any game"</p> }
+                        .into_any();
+                }
+                entries
+                    .into_iter()
+                    .map(|entry| match entry {
+                        Ok(entry) => view! {
+                            <li class="flex flex-wrap items-center justify-between gap-3 p-4 border-b border-gray-300 dark:border-gray-600">
+                                <div class="break-all">
+                                    <p class="font-medium">{format!("{} {}", entry.game, entry.id)}</p>
+                                    <p class="text-sm text-gray-600 dark:text-gray-400">
+                                        {format!("{:?} period. {}", entry.period, entry.task.label())}
+                                    </p>
+                                    {entry
+                                        .deadline
+                                        .map(|end_block| {
+                                            view! {
+                                                <p class="text-sm">
+                                                    "Period ends: " <BlockCountdown end_block=end_block />
+                                                </p>
+                                            }
+                                        })}
+                                </div>
+                                <a
+                                    href=entry.page
+                                    class="whitespace-nowrap text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                                >
+                                    {entry.task.action()}
+                                </a>
+                            </li>
+                        }
+                        .into_any(),
+                        Err(failed) => view! {
+                            <li class="flex flex-wrap items-center justify-between gap-3 p-4 border-b border-gray-300 dark:border-gray-600">
+                                <div class="break-all">
+                                    <p class="font-medium">{format!("{} {}", failed.game, failed.id)}</p>
+                                    <p class="text-sm text-red-700 dark:text-red-400">
+                                        {format!("Could not load this game: {}", failed.error)}
+                                    </p>
+                                </div>
+                                <a
+                                    href=failed.page
+                                    class="whitespace-nowrap text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                                >
+                                    "Open game"
+                                </a>
+                            </li>
+                        }
+                        .into_any(),
+                    })
+                    .collect::<Vec<_>>()
+                    .into_any()
+            })
+        })
+    };
+
+    view! {
+        <>
+            <Nav />
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <h1 class="text-2xl font-bold mb-4 text-center">Juror Dashboard</h1>
+                <AppErrorBoundary on_retry=move |_: ()| entries.refetch()>
+                    <ul>{move || entries_view()}</ul>
+                </AppErrorBoundary>
+            </main>
+        </>
+    }
+}
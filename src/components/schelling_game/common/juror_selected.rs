@@ -1,50 +1,50 @@
-use crate::constants::constant::NODE_URL;
-use jsonrpsee_core::{client::ClientT, rpc_params};
-use jsonrpsee_wasm_client::WasmClientBuilder;
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::services::chain_client::ChainClient;
+use crate::services::error::AppError;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
-async fn load_data(department_required_fund_id: u64, check_account: String) -> bool {
-    let client = WasmClientBuilder::default().build(NODE_URL).await.unwrap();
-    // gloo::console::log!(department_required_fund_id.clone(), check_account.clone());
-    let result: bool = client
-        .request(
-            "departmentfunding_selectedjuror",
-            rpc_params![department_required_fund_id, check_account],
-        )
-        .await
-        .unwrap();
-    result
+async fn load_data<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: K::Id,
+    check_account: String,
+) -> Result<bool, AppError> {
+    let rpc = chain_client.shivarthu_rpc().await?;
+    rpc.selected_juror(&K::game_key(&id), check_account).await
 }
 
 #[component]
-pub fn JurorSelected(
-    department_required_fund_id: u64,
+pub fn JurorSelected<K: SchellingGameKind>(
+    id: K::Id,
     check_account: ReadSignal<String>,
 ) -> impl IntoView {
-    let (data, set_data) = signal(None::<bool>);
+    let chain_client = expect_context::<ChainClient>();
+    let (data, set_data) = signal(None::<Result<bool, AppError>>);
+    let (reload, set_reload) = signal(0u32);
 
     Effect::new(move |_| {
-        let department_required_fund_id = department_required_fund_id.clone();
+        let id = id.clone();
         let account = check_account.get(); // Reactive dependency on `check_account`
+        reload.track();
 
         // Spawn an asynchronous task to fetch data
         spawn_local(async move {
-            let result = load_data(department_required_fund_id, account).await;
+            let result = load_data::<K>(chain_client, id, account).await;
             set_data.set(Some(result)); // Update the signal with the fetched data
         });
     });
 
     // Define the reactive view based on the current state of `data`
     let async_result = move || {
-        data.get().as_ref().map_or_else(
+        data.get().map_or_else(
             || {
                 // Loading state
-                view! { <div></div> }
-                .into_any()
+                Ok(view! { <div></div> }
+                .into_any())
             },
-            |data| {
-                if *data == false {
+            |data| data.map(|data| {
+                if !data {
                     view! {
                         <div
                             role="alert"
@@ -65,8 +65,13 @@ pub fn JurorSelected(
                     }
                     .into_any()
                 }
-            },
+            }),
         )
     };
-    view! { <div>{async_result}</div> }
+    let retry = move |_: ()| set_reload.update(|n| *n += 1);
+    view! {
+        <div>
+            <AppErrorBoundary on_retry=retry>{async_result}</AppErrorBoundary>
+        </div>
+    }
 }
@@ -0,0 +1,70 @@
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::get_period_fn::current_sum_tree_name;
+use crate::services::chain_client::ChainClient;
+use crate::services::common_services::{account_id_from_str, polkadot};
+use crate::services::error::AppError;
+use leptos::prelude::*;
+use polkadot::runtime_types::pallet_sortition_sum_game::types::SumTreeName;
+use subxt::utils::AccountId32;
+
+/// Stake of `account` in the game's current round, if a round has started.
+///
+/// Read from the round's sortition tree, so it works for every game, unlike
+/// the `*_has_user_staked` RPC that only positive externality serves.
+pub async fn stored_stake<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: &K::Id,
+    account: &str,
+) -> Result<Option<u64>, AppError> {
+    let Some(key) = current_sum_tree_name::<K>(chain_client, id).await? else {
+        return Ok(None);
+    };
+    round_stake(chain_client, key, &account_id_from_str(account)?)
+        .await
+        .map(Some)
+}
+
+/// Stake of `account_id` in the round `key`, zero if it has not staked.
+pub async fn round_stake(
+    chain_client: ChainClient,
+    key: SumTreeName,
+    account_id: &AccountId32,
+) -> Result<u64, AppError> {
+    let storage = chain_client.online().await?.storage().at_latest().await?;
+    let Some(tree) = storage
+        .fetch(
+            &polkadot::storage()
+                .sortition_sum_game()
+                .sortition_sum_trees(key),
+        )
+        .await?
+    else {
+        return Ok(0);
+    };
+
+    Ok(tree
+        .ids_to_node_indexes
+        .iter()
+        .find(|(juror, _)| juror == account_id)
+        .and_then(|(_, index)| tree.nodes.get(*index as usize).copied())
+        .unwrap_or_default())
+}
+
+/// Loads the signed-in account's stake, reloading when the account changes.
+pub fn stored_stake_fn<K: SchellingGameKind>(
+    id: K::Id,
+    account: impl Fn() -> String + 'static,
+) -> LocalResource<Result<Option<u64>, AppError>> {
+    let chain_client = expect_context::<ChainClient>();
+
+    LocalResource::new(move || {
+        let id = id.clone();
+        let account = account();
+        async move {
+            if account.is_empty() {
+                return Ok(None);
+            }
+            stored_stake::<K>(chain_client, &id, &account).await
+        }
+    })
+}
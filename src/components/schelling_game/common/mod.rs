@@ -0,0 +1,26 @@
+pub mod apply_jurors;
+pub mod apply_staking_period;
+pub mod challenger_fees;
+pub mod change_period;
+pub mod commit_vote;
+pub mod draw_jurors;
+pub mod drawing_period_end;
+pub mod game_kind;
+pub mod game_timeline;
+pub mod game_transaction;
+pub mod games;
+pub mod get_incentives;
+pub mod get_period;
+pub mod get_period_fn;
+pub mod has_user_staked;
+pub mod juror_dashboard;
+pub mod juror_selected;
+pub mod juror_stake_fn;
+pub mod period_end_block;
+pub mod reveal_vote;
+pub mod score_results;
+pub mod total_fund_collected;
+pub mod unstaking;
+pub mod user_staked_value;
+pub mod vote_commit_fn;
+pub mod vote_vault;
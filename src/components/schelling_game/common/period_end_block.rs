@@ -0,0 +1,105 @@
+use crate::components::common::block_countdown::BlockCountdown;
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::services::chain_client::ChainClient;
+use crate::services::chain_head::ChainHead;
+use crate::services::error::AppError;
+use icondata;
+use leptos::prelude::*;
+use leptos_icons::*;
+
+/// Periods whose end block the node reports over RPC.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PeriodEnd {
+    Evidence,
+    Staking,
+    Commit,
+    Vote,
+}
+
+impl PeriodEnd {
+    fn label(&self) -> &'static str {
+        match self {
+            PeriodEnd::Evidence => "Evidence Period ends: ",
+            PeriodEnd::Staking => "Staking Period ends: ",
+            PeriodEnd::Commit => "Commit Period ends: ",
+            PeriodEnd::Vote => "Vote Period ends: ",
+        }
+    }
+}
+
+async fn load_data<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: K::Id,
+    period: PeriodEnd,
+) -> Result<Option<u32>, AppError> {
+    let rpc = chain_client.shivarthu_rpc().await?;
+    let key = K::game_key(&id);
+    match period {
+        PeriodEnd::Evidence => rpc.evidence_end_block(&key).await,
+        PeriodEnd::Staking => rpc.staking_end_block(&key).await,
+        PeriodEnd::Commit => rpc.commit_end_block(&key).await,
+        PeriodEnd::Vote => rpc.vote_end_block(&key).await,
+    }
+}
+
+/// Block at which `period` ends, with a countdown to it.
+#[component]
+pub fn PeriodEndBlock<K: SchellingGameKind>(id: K::Id, period: PeriodEnd) -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
+    let (end_period, set_end_period) = signal::<Option<Result<Option<u32>, AppError>>>(None);
+
+    let action: Action<K::Id, ()> = Action::new_unsync(move |id: &K::Id| {
+        let id = id.clone();
+        async move { set_end_period(Some(load_data::<K>(chain_client, id, period).await)) }
+    });
+
+    // Re-query once on mount and then whenever a new block is finalized.
+    let current_block = expect_context::<ChainHead>().current_block();
+    Effect::new({
+        let id = id.clone();
+        move |_| {
+            current_block.track();
+            action.dispatch(id.clone());
+        }
+    });
+
+    let retry = move |_: ()| {
+        action.dispatch(id.clone());
+    };
+
+    view! {
+        <AppErrorBoundary on_retry=retry>
+            {move || {
+                end_period()
+                    .map(|result| {
+                        result
+                            .map(|end_block| {
+                                view! {
+                                    <div>
+                                        {period.label()}
+                                        {end_block
+                                            .map(|end_block| {
+                                                view! { <BlockCountdown end_block=end_block /> }
+                                            })}
+                                    </div>
+                                }
+                                    .into_any()
+                            })
+                    })
+                    .unwrap_or_else(|| {
+                        Ok(
+                            view! {
+                                <div>
+                                    {period.label()} <span id="end-period-time">
+                                        <Icon icon=icondata::ImSpinner6 style="color: green" />
+                                    </span>
+                                </div>
+                            }
+                                .into_any(),
+                        )
+                    })
+            }}
+        </AppErrorBoundary>
+    }
+}
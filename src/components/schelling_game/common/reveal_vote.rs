@@ -0,0 +1,346 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
+use crate::components::schelling_game::common::change_period::ChangePeriod;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::game_transaction::GameTransaction;
+use crate::components::schelling_game::common::get_period::GetPeriod;
+use crate::components::schelling_game::common::period_end_block::{PeriodEnd, PeriodEndBlock};
+use crate::components::schelling_game::common::vote_commit_fn::stored_commit_fn;
+use crate::services::common_imp::View;
+use crate::services::error::AppError;
+use crate::services::vote_vault::{before_key_derivation, vote_commit, VoteVault, VOTE_VAULT_KEY};
+use codee::string::JsonSerdeCodec;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::storage::use_local_storage;
+use reactive_stores::Store;
+
+/// Reveals a committed vote. A vote saved in the vote vault by
+/// [`CommitVote`](super::commit_vote::CommitVote) fills in the choice and
+/// salt once its password is given.
+///
+/// Before submitting, the commitment is recomputed from the choice and salt
+/// and compared with the juror's commit on chain, so a typo does not cost a
+/// failed extrinsic.
+#[component]
+pub fn RevealVote<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+    let account = state.account_address();
+    let (vault, _set_vault, _reset_vault) =
+        use_local_storage::<VoteVault, JsonSerdeCodec>(VOTE_VAULT_KEY);
+    let (current_view, set_current_view) = signal(View::Form);
+    let (choice, set_choice) = signal::<Result<Option<K::Choice>, AppError>>(Ok(None));
+    let (choice_input, set_choice_input) = signal(String::new());
+    let (salt, set_salt) = signal(String::from(""));
+    let (vault_password, set_vault_password) = signal(String::new());
+    let (vault_message, set_vault_message) = signal(String::new());
+    let (opening, set_opening) = signal(false);
+    let (commit_check, set_commit_check) = signal::<Result<(), AppError>>(Ok(()));
+    let game_id = StoredValue::new(id.to_string());
+    let stored = stored_commit_fn::<K>(id.clone(), move || account.get());
+
+    let saved_votes = move || {
+        let address = account.get();
+        vault.with(|vault| {
+            game_id.with_value(|game_id| {
+                vault
+                    .find(K::PALLET, game_id, &address)
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+    let check_commit = move |choice: &K::Choice| -> Result<(), AppError> {
+        let stored = match stored.get_untracked() {
+            Some(result) => result?,
+            None => {
+                return Err(AppError::Validation(
+                    "Still reading your commit from the chain, try again in a moment".to_string(),
+                ))
+            }
+        };
+        let Some(stored) = stored else {
+            return Err(AppError::Validation(
+                "No vote was committed by this account in the current round".to_string(),
+            ));
+        };
+        if stored.revealed {
+            return Err(AppError::Validation(
+                "This account has already revealed its vote".to_string(),
+            ));
+        }
+        if vote_commit(&choice.to_string(), &salt()) != stored.commit {
+            return Err(AppError::Validation(
+                "The choice and salt do not match the commit on chain. Check both against your saved vote".to_string(),
+            ));
+        }
+        Ok(())
+    };
+
+    let submit_click = move |e: SubmitEvent| {
+        e.prevent_default();
+        match choice() {
+            Ok(Some(choice)) => {
+                let check = check_commit(&choice);
+                if check.is_ok() {
+                    set_current_view(View::Success);
+                }
+                set_commit_check(check);
+            }
+            Ok(None) => set_choice(Err(AppError::Validation("Choice not set".to_string()))),
+            Err(_) => {}
+        }
+    };
+
+    let choice_changed = move |value: String| {
+        let choice_value = value
+            .parse::<K::Choice>()
+            .map_err(|e| AppError::Validation(format!("Invalid choice: {e}")))
+            .and_then(|choice| {
+                let known = K::CHOICES
+                    .iter()
+                    .any(|(known, _)| known.to_string() == choice.to_string());
+                if K::SCORE_GAME && !known {
+                    let (first, _) = &K::CHOICES[0];
+                    let (last, _) = &K::CHOICES[K::CHOICES.len() - 1];
+                    return Err(AppError::Validation(format!(
+                        "Score {choice} is outside {first} to {last}, the pallet refuses to reveal it"
+                    )));
+                }
+                Ok(Some(choice))
+            });
+
+        set_choice_input(value);
+        set_choice(choice_value);
+        set_commit_check(Ok(()));
+    };
+    if K::SCORE_GAME {
+        choice_changed(K::CHOICES[K::CHOICES.len() / 2].0.to_string());
+    }
+
+    // The saved vote matching the commit on chain wins, otherwise the newest
+    // one that opens with the password.
+    let open_vault = move || async move {
+        let on_chain = stored
+            .get_untracked()
+            .and_then(|stored| stored.ok().flatten())
+            .map(|stored| format!("0x{}", hex::encode(stored.commit)));
+        let password = vault_password.get_untracked();
+        let mut opened = Vec::new();
+        let mut last_error = None;
+        for entry in saved_votes() {
+            before_key_derivation().await;
+            match entry.open(&password) {
+                Ok(secret) => opened.push(secret),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        let secret = match opened
+            .iter()
+            .position(|secret| Some(&secret.commit) == on_chain.as_ref())
+        {
+            Some(index) => Some(opened.swap_remove(index)),
+            None => opened.into_iter().next(),
+        };
+        match (secret, last_error) {
+            (Some(secret), _) => {
+                choice_changed(secret.choice);
+                set_salt(secret.salt);
+                set_commit_check(Ok(()));
+                set_vault_password(String::new());
+                set_vault_message("Filled in from the saved vote.".to_string());
+            }
+            (None, Some(e)) => set_vault_message(e.to_string()),
+            (None, None) => set_vault_message(String::new()),
+        }
+    };
+    let fill_from_vault = move |_| {
+        if opening.get_untracked() {
+            return;
+        }
+        set_opening(true);
+        set_vault_message("Opening the saved vote...".to_string());
+        spawn_local(async move {
+            open_vault().await;
+            set_opening(false);
+        });
+    };
+
+    let render_view = move || {
+        match current_view() {
+            View::Form => {
+                view! {
+                    <div class="max-w-5xl mx-auto max-md:mx-10">
+                        <div class="text-gray-900 dark:text-white">
+                            <GetPeriod<K> id=id.clone() />
+                            <PeriodEndBlock<K> id=id.clone() period=PeriodEnd::Vote />
+                            <ChangePeriod<K> id=id.clone() />
+                            <AppErrorBoundary on_retry=move |_: ()| stored.refetch()>
+                                <p>
+                                    {move || {
+                                        stored
+                                            .get()
+                                            .transpose()
+                                            .map(|stored| match stored.flatten() {
+                                                Some(stored) => format!(
+                                                    "Your commit: 0x{} ({})",
+                                                    hex::encode(stored.commit),
+                                                    if stored.revealed { "already revealed" } else { "not revealed yet" },
+                                                ),
+                                                None if account.get().is_empty() => String::new(),
+                                                None => "No commit found for your account in this round".to_string(),
+                                            })
+                                    }}
+                                </p>
+                            </AppErrorBoundary>
+                        </div>
+                        {move || {
+                            (!saved_votes().is_empty())
+                                .then(|| {
+                                    view! {
+                                        <div class="mb-5 p-4 rounded-lg border border-gray-300 dark:border-gray-600">
+                                            <label
+                                                for="vault-password"
+                                                class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                            >
+                                                "A saved vote was found. Password of the saved vote"
+                                            </label>
+                                            <div class="flex gap-2">
+                                                <input
+                                                    type="password"
+                                                    id="vault-password"
+                                                    class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                                    prop:value=move || vault_password()
+                                                    on:input=move |ev| {
+                                                        set_vault_password(event_target_value(&ev));
+                                                        set_vault_message(String::new());
+                                                    }
+                                                />
+                                                <button
+                                                    type="button"
+                                                    id="fill-from-vault"
+                                                    class="whitespace-nowrap text-white bg-blue-700 hover:bg-blue-800 font-medium rounded-lg text-sm px-5 py-2.5 dark:bg-blue-600 dark:hover:bg-blue-700 disabled:opacity-50"
+                                                    disabled=opening
+                                                    on:click=fill_from_vault
+                                                >
+                                                    Fill in
+                                                </button>
+                                            </div>
+                                            <p class="mt-2 text-sm text-gray-600 dark:text-gray-400">
+                                                {vault_message}
+                                            </p>
+                                        </div>
+                                    }
+                                })
+                        }}
+                        <form id="reveal-vote-submit-from" on:submit=submit_click>
+
+                            <div class="mb-5">
+                                <label
+                                    for="choice"
+                                    class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                >
+                                    {if K::SCORE_GAME { "Score" } else { "Choice" }}
+                                </label>
+                                {if K::SCORE_GAME {
+                                    let index = move || {
+                                        K::CHOICES
+                                            .iter()
+                                            .position(|(choice, _)| choice.to_string() == choice_input())
+                                    };
+                                    view! {
+                                        <div class="flex items-center gap-3">
+                                            <input
+                                                type="range"
+                                                id="choice"
+                                                class="w-full accent-blue-600"
+                                                min="0"
+                                                max=(K::CHOICES.len() - 1).to_string()
+                                                step="1"
+                                                prop:value=move || index().unwrap_or_default().to_string()
+                                                on:input=move |ev| {
+                                                    if let Some((choice, _)) = event_target_value(&ev)
+                                                        .parse::<usize>()
+                                                        .ok()
+                                                        .and_then(|index| K::CHOICES.get(index))
+                                                    {
+                                                        choice_changed(choice.to_string());
+                                                    }
+                                                }
+                                            />
+                                            <span class="w-8 text-center font-medium">
+                                                {move || index().map_or("-", |index| K::CHOICES[index].1)}
+                                            </span>
+                                        </div>
+                                    }
+                                        .into_any()
+                                } else {
+                                    view! {
+                                        <input
+                                            type="number"
+                                            id="choice"
+                                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                            required
+                                            prop:value=move || choice_input()
+                                            on:input=move |ev| choice_changed(event_target_value(&ev))
+                                        />
+                                    }
+                                        .into_any()
+                                }}
+                            </div>
+                            <div class="mb-5">
+                                <label
+                                    for="salt"
+                                    class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                >
+                                    Salt
+                                </label>
+                                <input
+                                    type="text"
+                                    id="salt"
+                                    class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                    required
+                                    prop:value=move || salt()
+                                    on:input=move |ev| {
+                                        set_salt(event_target_value(&ev));
+                                        set_commit_check(Ok(()));
+                                    }
+                                />
+                            </div>
+                            <button
+                                type="submit"
+                                id="reveal-vote-submit"
+                                class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                            >
+
+                                Submit
+                            </button>
+                        </form>
+                        <br />
+                        <AppErrorBoundary>
+                            {move || choice().map(|_| ())}
+                            {move || commit_check()}
+                        </AppErrorBoundary>
+                    </div>
+                }.into_any()
+            }
+            View::Success => {
+                view! {
+                    <div>
+                        <GameTransaction tx=K::reveal_vote(
+                            &id,
+                            choice().ok().flatten().unwrap_or_default(),
+                            salt().as_bytes().to_vec(),
+                        ) />
+
+                    </div>
+                }.into_any()
+            }
+
+        }
+    };
+
+    view! { <div>{move || render_view()}</div> }
+}
@@ -0,0 +1,99 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::get_period_fn::current_sum_tree_name;
+use crate::services::chain_client::ChainClient;
+use crate::services::common_services::polkadot;
+use crate::services::error::AppError;
+use leptos::prelude::*;
+
+/// Revealed scores of a score game's current round.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScoreResultsData {
+    pub scores: Vec<i64>,
+    /// Mean of the revealed scores, set once incentives are counted.
+    pub incentive_mean: Option<i64>,
+}
+
+async fn load_data<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: K::Id,
+) -> Result<Option<ScoreResultsData>, AppError> {
+    let Some(key) = current_sum_tree_name::<K>(chain_client, &id).await? else {
+        return Ok(None);
+    };
+
+    let storage = chain_client.online().await?.storage().at_latest().await?;
+    let games = polkadot::storage().schelling_game_shared();
+    let scores = storage
+        .fetch_or_default(&games.reveal_score_values(key.clone()))
+        .await?;
+    let incentive_mean = storage
+        .fetch(&games.incentive_mean_reveal_score(key))
+        .await?;
+    Ok(Some(ScoreResultsData {
+        scores,
+        incentive_mean,
+    }))
+}
+
+/// How many jurors revealed each score, and the mean used for incentives.
+#[component]
+pub fn ScoreResults<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
+    let results = LocalResource::new(move || load_data::<K>(chain_client, id.clone()));
+
+    let results_view = move || {
+        results.get().transpose().map(|data| {
+            let Some(data) = data.flatten() else {
+                return view! { <p>"The game has not started yet"</p> }.into_any();
+            };
+            let total = data.scores.len();
+            let rows = K::CHOICES
+                .iter()
+                .map(|(choice, label)| {
+                    let choice = choice.to_string();
+                    let count = data
+                        .scores
+                        .iter()
+                        .filter(|score| score.to_string() == choice)
+                        .count();
+                    let width = if total == 0 { 0 } else { count * 100 / total };
+                    view! {
+                        <div class="flex items-center gap-3 mb-2">
+                            <span class="w-8 text-right font-medium">{*label}</span>
+                            <div class="flex-1 h-4 bg-gray-200 rounded dark:bg-gray-700">
+                                <div
+                                    class="h-4 bg-blue-600 rounded dark:bg-blue-500"
+                                    style=format!("width: {width}%")
+                                ></div>
+                            </div>
+                            <span class="w-8">{count}</span>
+                        </div>
+                    }
+                })
+                .collect::<Vec<_>>();
+            view! {
+                <div>
+                    <p class="mb-3">{format!("{total} scores revealed")}</p>
+                    {rows}
+                    <p class="mt-3">
+                        {match data.incentive_mean {
+                            Some(mean) => format!("Mean used for incentives: {mean}"),
+                            None => "The mean is set when incentives are counted".to_string(),
+                        }}
+                    </p>
+                </div>
+            }
+            .into_any()
+        })
+    };
+
+    view! {
+        <div class="max-w-5xl mx-auto max-md:mx-10 my-5 text-gray-900 dark:text-white">
+            <h2 class="text-xl font-semibold mb-3">Scores</h2>
+            <AppErrorBoundary on_retry=move |_: ()| results.refetch()>
+                {move || results_view()}
+            </AppErrorBoundary>
+        </div>
+    }
+}
@@ -0,0 +1,73 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::services::chain_client::ChainClient;
+use crate::services::error::AppError;
+use leptos::prelude::*;
+
+async fn load_data<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: K::Id,
+) -> Result<(u128, u128, u128), AppError> {
+    let client = chain_client.online().await?;
+    let fund_collected_storage = subxt::dynamic::storage(
+        K::PALLET,
+        "ProfileTotalFundCollected",
+        vec![K::id_value(&id)?],
+    );
+    let registration_fee_storage = subxt::dynamic::storage(K::PALLET, "RegistrationFee", ());
+
+    let storage = client.storage().at_latest().await?;
+    let fund_collected_value = storage
+        .fetch_or_default(&fund_collected_storage)
+        .await?
+        .as_type::<u128>()
+        .map_err(|e| AppError::Decode(e.to_string()))?;
+    let registration_fee_value = storage
+        .fetch_or_default(&registration_fee_storage)
+        .await?
+        .as_type::<u128>()
+        .map_err(|e| AppError::Decode(e.to_string()))?;
+
+    let fund_needed = registration_fee_value.saturating_sub(fund_collected_value);
+    Ok((fund_collected_value, registration_fee_value, fund_needed))
+}
+
+/// Funds collected towards the registration fee of a `K` game entry.
+#[component]
+pub fn TotalFundCollected<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
+
+    let async_load = LocalResource::new(move || load_data::<K>(chain_client, id.clone()));
+    let async_result = move || {
+        async_load
+            .get()
+            .map(|data| {
+                data.map(|data| {
+                    view! {
+                        <div>Total fund collected: {data.0}</div>
+                        <div>Registration fee: {data.1}</div>
+                        <div>Fund needed : {data.2}</div>
+                    }
+                    .into_any()
+                })
+            })
+            // This loading state will only show before the first load
+            .unwrap_or_else(|| {
+                Ok(view! {
+                    <p>
+                        <span class="loading loading-spinner text-primary"></span>
+                        Loading...
+                    </p>
+                }
+                .into_any())
+            })
+    };
+
+    view! {
+        <div>
+            <AppErrorBoundary on_retry=move |_: ()| async_load.refetch()>
+                {async_result}
+            </AppErrorBoundary>
+        </div>
+    }
+}
@@ -1,11 +1,11 @@
-use crate::components::schelling_game::project_tips::unstaking_sign_in::SignTransaction;
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::game_transaction::GameTransaction;
 use crate::services::common_imp::View;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 
 #[component]
-pub fn Unstaking(project_id: u64) -> impl IntoView {
-    // gloo::console::log!(project_id());
+pub fn Unstaking<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
     let (current_view, set_current_view) = signal(View::Form);
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
@@ -33,12 +33,12 @@ pub fn Unstaking(project_id: u64) -> impl IntoView {
             View::Success => {
                 view! {
                     <div>
-                        <SignTransaction project_id=project_id.clone() />
+                        <GameTransaction tx=K::unstaking(&id) />
 
                     </div>
                 }.into_any()
             }
-       
+
         }
     };
 
@@ -0,0 +1,37 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::juror_stake_fn::stored_stake_fn;
+use leptos::prelude::*;
+use reactive_stores::Store;
+
+/// Juror stake of the current account, watch-only included.
+#[component]
+pub fn UserStakedValue<K: SchellingGameKind>(id: K::Id) -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+    let account = state.account_address();
+
+    let async_data = stored_stake_fn::<K>(id, move || account.get());
+
+    let async_result = move || {
+        async_data.get().transpose().map(|data| {
+            data.flatten().map(|staked| {
+                view! {
+                    <div
+                        role="alert"
+                        class="flex items-center gap-3 p-4 border-l-4 border-green-500 bg-green-100 text-green-800 rounded-xl shadow-md"
+                    >
+                        <p>{format!("Staked amount: {staked}")}</p>
+                    </div>
+                }
+            })
+        })
+    };
+    view! {
+        <div>
+            <AppErrorBoundary on_retry=move |_: ()| async_data.refetch()>
+                {async_result}
+            </AppErrorBoundary>
+        </div>
+    }
+}
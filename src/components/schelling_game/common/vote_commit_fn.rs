@@ -0,0 +1,74 @@
+use crate::components::schelling_game::common::game_kind::SchellingGameKind;
+use crate::components::schelling_game::common::get_period_fn::current_sum_tree_name;
+use crate::services::chain_client::ChainClient;
+use crate::services::common_services::{account_id_from_str, polkadot};
+use crate::services::error::AppError;
+use leptos::prelude::*;
+use polkadot::runtime_types::pallet_schelling_game_shared::types::VoteStatus;
+use polkadot::runtime_types::pallet_sortition_sum_game::types::SumTreeName;
+use subxt::utils::AccountId32;
+
+/// A juror's commit as stored by `pallet_schelling_game_shared`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoredCommit {
+    pub commit: [u8; 32],
+    pub revealed: bool,
+}
+
+/// Commit of `account` in the game's current round, if the account has
+/// committed a vote.
+pub async fn stored_commit<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    id: &K::Id,
+    account: &str,
+) -> Result<Option<StoredCommit>, AppError> {
+    let Some(key) = current_sum_tree_name::<K>(chain_client, id).await? else {
+        return Ok(None);
+    };
+    round_commit::<K>(chain_client, key, account_id_from_str(account)?).await
+}
+
+/// Commit of `account_id` in the round `key` of a `K` game.
+pub async fn round_commit<K: SchellingGameKind>(
+    chain_client: ChainClient,
+    key: SumTreeName,
+    account_id: AccountId32,
+) -> Result<Option<StoredCommit>, AppError> {
+    let storage = chain_client.online().await?.storage().at_latest().await?;
+    let games = polkadot::storage().schelling_game_shared();
+    let commit = if K::SCORE_GAME {
+        storage
+            .fetch(&games.score_vote_commits(key, account_id))
+            .await?
+            .map(|vote| (vote.commit, vote.votestatus))
+    } else {
+        storage
+            .fetch(&games.vote_commits(key, account_id))
+            .await?
+            .map(|vote| (vote.commit, vote.votestatus))
+    };
+
+    Ok(commit.map(|(commit, status)| StoredCommit {
+        commit,
+        revealed: matches!(status, VoteStatus::Revealed),
+    }))
+}
+
+/// Loads the signed-in account's commit, reloading when the account changes.
+pub fn stored_commit_fn<K: SchellingGameKind>(
+    id: K::Id,
+    account: impl Fn() -> String + 'static,
+) -> LocalResource<Result<Option<StoredCommit>, AppError>> {
+    let chain_client = expect_context::<ChainClient>();
+
+    LocalResource::new(move || {
+        let id = id.clone();
+        let account = account();
+        async move {
+            if account.is_empty() {
+                return Ok(None);
+            }
+            stored_commit::<K>(chain_client, &id, &account).await
+        }
+    })
+}
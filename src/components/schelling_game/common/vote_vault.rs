@@ -0,0 +1,146 @@
+use crate::components::navigation::nav::Nav;
+use crate::services::vote_vault::{VoteVault, VOTE_VAULT_KEY};
+use codee::string::JsonSerdeCodec;
+use gloo::file::{Blob, ObjectUrl};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::storage::use_local_storage;
+
+/// Lists the saved votes and moves them between devices. Entries stay
+/// encrypted in the exported file.
+#[component]
+pub fn ManageVoteVault() -> impl IntoView {
+    let (vault, set_vault, _reset_vault) =
+        use_local_storage::<VoteVault, JsonSerdeCodec>(VOTE_VAULT_KEY);
+    let (status, set_status) = signal(None::<Result<String, String>>);
+    let (download, set_download) = signal(None::<(String, String)>);
+    // Revokes the previous blob URL when replaced or when the page is left.
+    let object_url = StoredValue::new_local(None::<ObjectUrl>);
+
+    let export = move |_| match vault.with_untracked(|vault| vault.to_json()) {
+        Ok(json) => {
+            let url = ObjectUrl::from(Blob::new_with_options(
+                json.as_str(),
+                Some("application/json"),
+            ));
+            set_download(Some((
+                url.to_string(),
+                format!("shivarthu-votes-{}.json", js_sys::Date::now() as u64),
+            )));
+            object_url.set_value(Some(url));
+            set_status(None);
+        }
+        Err(e) => set_status(Some(Err(e.to_string()))),
+    };
+
+    let import = move |e: leptos::ev::Event| {
+        set_status(None);
+        let input = event_target::<web_sys::HtmlInputElement>(&e);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            let result = gloo::file::futures::read_as_text(&gloo::file::File::from(file))
+                .await
+                .map_err(|e| format!("Cannot read the file: {e}"))
+                .and_then(|text| VoteVault::parse(&text).map_err(|e| e.to_string()));
+            match result {
+                Ok(imported) => {
+                    let mut current = vault.get_untracked();
+                    let added = current.merge(imported);
+                    set_vault.set(current);
+                    set_status(Some(Ok(format!("Imported {added} votes."))));
+                }
+                Err(e) => set_status(Some(Err(e))),
+            }
+        });
+    };
+
+    let rows = move || {
+        vault
+            .get()
+            .entries
+            .into_iter()
+            .map(|entry| {
+                view! {
+                    <li class="p-2 border-b border-gray-300 dark:border-gray-600 break-all">
+                        <p class="font-medium">{format!("{} {}", entry.game, entry.game_id)}</p>
+                        <p class="text-sm text-gray-500 dark:text-gray-400">{entry.account}</p>
+                    </li>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <>
+            <Nav />
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <h1 class="text-2xl font-bold mb-4 text-center">Vote Vault</h1>
+                <ul class="mb-8">{rows}</ul>
+
+                <h2 class="text-xl font-semibold mb-2">Export</h2>
+                <button
+                    type="button"
+                    class="mb-2 text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800 disabled:opacity-50"
+                    disabled=move || vault.with(|vault| vault.entries.is_empty())
+                    on:click=export
+                >
+                    Export Votes
+                </button>
+                {move || {
+                    download()
+                        .map(|(href, file_name)| {
+                            view! {
+                                <p class="mb-8">
+                                    <a
+                                        href=href
+                                        download=file_name.clone()
+                                        class="text-blue-600 underline dark:text-blue-400"
+                                    >
+                                        {format!("Download {file_name}")}
+                                    </a>
+                                </p>
+                            }
+                        })
+                }}
+
+                <h2 class="text-xl font-semibold mb-2 mt-8">Import</h2>
+                <label
+                    for="vote-vault-file"
+                    class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                >
+                    Vote vault file
+                </label>
+                <input
+                    type="file"
+                    id="vote-vault-file"
+                    accept=".json,application/json"
+                    class="block w-full text-sm text-gray-900 dark:text-white"
+                    on:change=import
+                />
+                {move || {
+                    status
+                        .get()
+                        .map(|status| {
+                            let (class, message) = match status {
+                                Ok(message) => (
+                                    "p-4 mt-4 border-l-4 border-green-500 bg-green-100 text-green-800 rounded-xl dark:bg-green-900 dark:text-green-200",
+                                    message,
+                                ),
+                                Err(message) => (
+                                    "p-4 mt-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl dark:bg-yellow-900 dark:text-yellow-200",
+                                    message,
+                                ),
+                            };
+                            view! {
+                                <div role="alert" class=class>
+                                    {message}
+                                </div>
+                            }
+                        })
+                }}
+            </main>
+        </>
+    }
+}
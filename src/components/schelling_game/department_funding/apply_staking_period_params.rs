@@ -1,4 +1,5 @@
-use crate::components::schelling_game::department_funding::apply_staking_period::ApplyStakingPeriod;
+use crate::components::schelling_game::common::apply_staking_period::ApplyStakingPeriod;
+use crate::components::schelling_game::common::games::DepartmentFundingGame;
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
 
@@ -19,7 +20,7 @@ pub fn ApplyStakingPeriodParams() -> impl IntoView {
 
     view! {
         <div>
-            <ApplyStakingPeriod department_required_fund_id=params_value />
+            <ApplyStakingPeriod<DepartmentFundingGame> id=params_value />
         </div>
     }
 }
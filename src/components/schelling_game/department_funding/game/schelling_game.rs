@@ -1,11 +1,17 @@
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::components::navigation::nav::Nav;
-use crate::components::schelling_game::department_funding::apply_jurors::ApplyJurors;
-
-use crate::components::schelling_game::department_funding::commit_vote::CommitVote;
-use crate::components::schelling_game::department_funding::draw_jurors::DrawJurors;
-use crate::components::schelling_game::department_funding::reveal_vote::RevealVote;
-use crate::components::schelling_game::department_funding::storage::get_period_fn::get_period_fn;
+use crate::components::schelling_game::common::apply_jurors::ApplyJurors;
+use crate::components::schelling_game::common::commit_vote::CommitVote;
+use crate::components::schelling_game::common::draw_jurors::DrawJurors;
+use crate::components::schelling_game::common::games::DepartmentFundingGame;
+use crate::components::schelling_game::common::get_incentives::GetIncentives;
+use crate::components::schelling_game::common::game_timeline::GameTimeline;
+use crate::components::schelling_game::common::get_period_fn::get_period_fn;
+use crate::components::schelling_game::common::reveal_vote::RevealVote;
+use crate::components::schelling_game::common::unstaking::Unstaking;
+use crate::components::schelling_game::department_funding::get_department_funding::GetDepartmentFunding;
 use crate::services::common_services::polkadot::runtime_types::pallet_schelling_game_shared::types::Period;
+use crate::services::error::AppError;
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
 
@@ -33,37 +39,38 @@ pub fn SchellingGame() -> impl IntoView {
 
 #[component]
 pub fn SchellingGameComponent(department_required_fund_id: u64) -> impl IntoView {
-    let department_required_fund_id = move || department_required_fund_id.clone();
-
-    let period = get_period_fn(department_required_fund_id());
+    let period = get_period_fn::<DepartmentFundingGame>(department_required_fund_id);
 
-    let myview = move || {
+    let myview = move || -> Result<AnyView, AppError> {
         {
             {
-                // let period_read_signal = period();
-                if let Some(period) = period() {
+                let Some(result) = period.get() else {
+                    return Ok(view! { <div></div> }.into_any());
+                };
+                let period = result?;
+                if let Some(period) = period {
                     let view = match period {
                         Period::Evidence => view! { <div></div> }.into_any(),
                         Period::Staking => {
                             view! {
                                 <div>
-                                    <ApplyJurors department_required_fund_id=department_required_fund_id() />
+                                    <ApplyJurors<DepartmentFundingGame> id=department_required_fund_id />
                                 </div>
                             }.into_any()
                         }
                         Period::Drawing => view! {
                             <div>
-                                <DrawJurors department_required_fund_id=department_required_fund_id() />
+                                <DrawJurors<DepartmentFundingGame> id=department_required_fund_id />
                             </div>
                         }.into_any(),
                         Period::Commit => view! {
                             <div>
-                                <CommitVote department_required_fund_id=department_required_fund_id() />
+                                <CommitVote<DepartmentFundingGame> id=department_required_fund_id />
                             </div>
                         }.into_any(),
                         Period::Vote => view! {
                             <div>
-                                <RevealVote department_required_fund_id=department_required_fund_id() />
+                                <RevealVote<DepartmentFundingGame> id=department_required_fund_id />
                             </div>
                         }.into_any(),
                         Period::Appeal => view! { <div></div> }.into_any(),
@@ -73,20 +80,32 @@ pub fn SchellingGameComponent(department_required_fund_id: u64) -> impl IntoView
                                     <div class="bg-green-100 border border-green-400 text-green-700 px-4 py-3 rounded border text-[10px] sm:text-base">
                                         You are in Execution phase. Get your incentives
                                     </div>
+                                    <br />
+                                    <GetDepartmentFunding department_required_fund_id=department_required_fund_id />
+                                    <br />
+                                    <GetIncentives<DepartmentFundingGame> id=department_required_fund_id />
+                                    <br />
+                                    <Unstaking<DepartmentFundingGame> id=department_required_fund_id />
 
                                 </div>
                             }
                         }.into_any()
                     };
-                    view
+                    Ok(view)
                 } else {
-                    view! {
+                    Ok(view! {
                         <div class="container mx-auto">
-                            <p class="text-gray-900 dark:text-white">{format!("{:?}", period())}</p>
+                            <p class="text-gray-900 dark:text-white">{format!("{:?}", period)}</p>
                             <p class="text-gray-900 dark:text-white">{"No period"}</p>
+                            <a
+                                href=format!("/department-funding/apply-staking-period/{}", department_required_fund_id)
+                                class="text-blue-600 hover:underline dark:text-blue-400"
+                            >
+                                "Start the staking period"
+                            </a>
                         </div>
                     }
-                    .into_any()
+                    .into_any())
                 }
             }
         }
@@ -95,9 +114,10 @@ pub fn SchellingGameComponent(department_required_fund_id: u64) -> impl IntoView
     view! {
         <div>
             <Nav />
-            // {move || account()}
-            // {move || format!("{:?}", period())}
-            {move || myview()}
+            <GameTimeline<DepartmentFundingGame> id=department_required_fund_id />
+            <AppErrorBoundary on_retry=move |_: ()| period.refetch()>
+                {move || myview()}
+            </AppErrorBoundary>
         </div>
     }
 }
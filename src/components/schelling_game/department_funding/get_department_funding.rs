@@ -1,11 +1,11 @@
-use crate::components::schelling_game::department_funding::get_department_funding_sign_in::SignTransaction;
+use crate::components::schelling_game::common::game_transaction::GameTransaction;
+use crate::components::schelling_game::common::games::{dynamic_call, DepartmentFundingGame};
 use crate::services::common_imp::View;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 
 #[component]
 pub fn GetDepartmentFunding(department_required_fund_id: u64) -> impl IntoView {
-    // gloo::console::log!(department_required_fund_id());
     let (current_view, set_current_view) = signal(View::Form);
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
@@ -33,8 +33,7 @@ pub fn GetDepartmentFunding(department_required_fund_id: u64) -> impl IntoView {
         View::Success => {
             view! {
                 <div>
-                    <SignTransaction department_required_fund_id=department_required_fund_id
-                        .clone() />
+                    <GameTransaction tx=dynamic_call::<DepartmentFundingGame>("add_to_department_fund", &department_required_fund_id, vec![]) />
 
                 </div>
             }.into_any()
@@ -1,25 +1,6 @@
-pub mod apply_jurors;
-pub mod apply_jurors_sign_in;
-pub mod apply_staking_period;
 pub mod apply_staking_period_params;
-pub mod apply_staking_period_sign_in;
-pub mod change_period;
-pub mod change_period_sign_in;
-pub mod commit_vote;
-pub mod commit_vote_sign_in;
 pub mod create_department_fund;
 pub mod create_department_fund_sign_in;
-pub mod draw_jurors;
-pub mod draw_jurors_sign_in;
 pub mod game;
 pub mod get_department_funding;
-pub mod get_department_funding_sign_in;
-pub mod get_incentives;
-pub mod get_incentives_sign_in;
-pub mod reveal_vote;
-pub mod reveal_vote_sign_in;
-pub mod rpc;
-pub mod storage;
-pub mod unstaking;
-pub mod unstaking_sign_in;
 pub mod views;
@@ -6,7 +6,6 @@ use leptos::prelude::*;
 
 #[component]
 pub fn AddMemberToDepartment(department_id: u64) -> impl IntoView {
-    // gloo::console::log!(user_to_calculate());
     let (current_view, set_current_view) = signal(View::Form);
 
     let (account_id, set_account_id) = signal(String::from(""));
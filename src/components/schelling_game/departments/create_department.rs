@@ -1,68 +1,27 @@
-use crate::components::api::ipfs_request::ipfs_call_json_string;
-use crate::components::api::select_ipfs_provider::DEFAULT_IPFS_PROVIDER;
-use crate::components::markdown::markdown_field::MarkdownField;
 use crate::components::navigation::nav::Nav;
 use crate::components::schelling_game::departments::create_department_sign_in::SignTransaction;
 use crate::services::common_imp::View;
-use json::object;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 
-async fn get_cid_post(
-    details: String,
-    title: String,
-    set_current_view: WriteSignal<View>,
-    set_post_cid: WriteSignal<String>,
-) {
-    let data = object! {
-          version: "1.0",
-          details: details,
-          title: title,
-    };
-    let json_string = json::stringify(data);
-    let response =
-        ipfs_call_json_string(DEFAULT_IPFS_PROVIDER, &json_string, "ipfs".to_owned()).await;
-    set_post_cid(response);
-    set_current_view(View::Success);
+#[component]
+pub fn SelectOption(is: &'static str, department_type: ReadSignal<String>) -> impl IntoView {
+    view! {
+        <option value=is selected=move || department_type() == is>
+            {is}
+        </option>
+    }
 }
 
 #[component]
 pub fn CreateDepartment() -> impl IntoView {
     let (current_view, set_current_view) = signal(View::Form);
-    let (title, set_title) = signal(String::from(""));
-    let (markdown, set_markdown) = signal(String::from(""));
-    let (post_cid, set_post_cid) = signal(String::from(""));
-
-    let submit_action: Action<
-        (String, String, WriteSignal<View>, WriteSignal<String>),
-        (),
-        LocalStorage,
-    > = Action::new_unsync(
-        |(details, title, set_current_view, set_post_cid): &(
-            String,
-            String,
-            WriteSignal<View>,
-            WriteSignal<String>,
-        )| {
-            let details = details.to_owned();
-            let title = title.to_owned();
-            let set_current_view = set_current_view.clone();
-            let set_post_cid = set_post_cid.clone();
-
-            async move { get_cid_post(details, title, set_current_view, set_post_cid).await }
-        },
-    );
-    let _submitted = submit_action.input();
-    let pending = submit_action.pending();
-    let submit_action_value = submit_action.value();
+    let (name, set_name) = signal(String::from(""));
+    let (department_type, set_department_type) = signal(String::from("District"));
 
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
-        submit_action.dispatch((markdown(), title(), set_current_view, set_post_cid));
-    };
-
-    let cid_value = move || {
-        submit_action_value();
+        set_current_view(View::Success);
     };
 
     let render_view = move || {
@@ -76,36 +35,36 @@ pub fn CreateDepartment() -> impl IntoView {
 
                         <div class="mb-5">
                             <label
-                                for="title"
+                                for="name"
                                 class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
                             >
-                                "Title"
+                                "Name"
                             </label>
                             <input
                                 type="text"
-                                id="title"
+                                id="name"
                                 class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
                                 required
-                                prop:value=move || title()
-                                on:input=move |e| set_title(event_target_value(&e))
+                                prop:value=move || name()
+                                on:input=move |e| set_name(event_target_value(&e))
                             />
                         </div>
 
                         <div class="mb-5">
                             <label
-                                for="create-department"
+                                for="department-type"
                                 class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
                             >
-                                "Department Details"
+                                "Department Type"
                             </label>
-                            <MarkdownField
-                                set_markdown=set_markdown
-                                name=String::from("department-details")
-                                class=String::from(
-                                    "bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500",
-                                )
-                            />
-
+                            <select
+                                on:change=move |ev| set_department_type(event_target_value(&ev))
+                                id="department-type"
+                                class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            >
+                                <SelectOption department_type is="District" />
+                                <SelectOption department_type is="Specialization" />
+                            </select>
                         </div>
 
                         <button
@@ -117,15 +76,13 @@ pub fn CreateDepartment() -> impl IntoView {
                         </button>
 
                     </form>
-                    <p>{move || pending().then(|| "Loading...")}</p>
-                    <p>{move || cid_value()}</p>
                 </div>
             }.into_any()
         }
 
         View::Success => view! {
             <div>
-                <SignTransaction post_cid=post_cid() />
+                <SignTransaction name=name() department_type=department_type() />
             </div>
         }.into_any(),
     }
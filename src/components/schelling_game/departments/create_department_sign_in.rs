@@ -1,134 +1,38 @@
-use crate::components::transaction::extension_sign_in::sign_in_with_extension;
-use crate::components::transaction::get_accounts_extension::GetAccountsExtension;
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::signing::sign_transaction_fn::SignTransactionFn;
 use crate::services::common_services::polkadot;
+use crate::services::error::AppError;
 use leptos::prelude::*;
-use polkadot::runtime_types::pallet_support::Content;
-
-#[component]
-pub fn SignTransaction(post_cid: String) -> impl IntoView {
-    view! { <ExtensionSignIn post_cid=post_cid /> }
+use polkadot::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+use polkadot::runtime_types::pallet_shared_storage::types::DepartmentType;
+
+fn match_department_type(department_type: &str) -> Result<DepartmentType, AppError> {
+    match department_type {
+        "District" => Ok(DepartmentType::District),
+        "Specialization" => Ok(DepartmentType::Specialization),
+        _ => Err(AppError::Validation(format!(
+            "Unknown department type: {:?}",
+            department_type
+        ))),
+    }
 }
 
 #[component]
-pub fn ExtensionSignIn(post_cid: String) -> impl IntoView {
-    let (account_load, set_account_load) = signal(("".to_owned(), "".to_owned()));
-
-    let render_html = move || {
-        if account_load().0.is_empty() || account_load().1.is_empty() {
-            view! {
-                <div>
-                    <GetAccountsExtension set_account_load=set_account_load />
-                </div>
-            }
-            .into_any()
-        } else if !account_load().0.is_empty() && !account_load().1.is_empty() {
-            view! {
-                <div>
-                    <ExtensionTransaction
-                        post_cid=post_cid.clone()
-                        account_address=account_load().0
-                        account_source=account_load().1
-                    />
-                </div>
-            }
-            .into_any()
-        } else {
-            view! { <div>{"Some Error Occured"}</div> }.into_any()
-        }
-    };
-    view! { <div>{move || render_html()}</div> }
-}
-
-async fn transaction(
-    post_cid: String,
-    account_address: String,
-    account_source: String,
-    set_error: WriteSignal<String>,
-    set_extrinsic_success: WriteSignal<String>,
-) {
-    let content: Content = Content::IPFS(post_cid.as_bytes().to_vec());
-
-    let tx = polkadot::tx().departments().create_department(content);
-
-    sign_in_with_extension(
-        tx,
-        account_address,
-        account_source,
-        set_error,
-        set_extrinsic_success,
-    )
-    .await;
+pub fn SignTransaction(name: String, department_type: String) -> impl IntoView {
+    view! { <ExtensionSignIn name=name department_type=department_type /> }
 }
 
 #[component]
-pub fn ExtensionTransaction(
-    post_cid: String,
-    account_address: String,
-    account_source: String,
-) -> impl IntoView {
-    let (error, set_error) = signal(String::from(""));
-    let (extrinsic_success, set_extrinsic_success) = signal(String::from(""));
-    let transaction_resource = LocalResource::new(move || {
-        transaction(
-            post_cid.clone(),
-            account_address.clone(),
-            account_source.clone(),
-            set_error,
-            set_extrinsic_success,
-        )
+pub fn ExtensionSignIn(name: String, department_type: String) -> impl IntoView {
+    let sign_transaction = match_department_type(&department_type).map(|department_type| {
+        let tx = Box::new(
+            polkadot::tx()
+                .shared_storage()
+                .create_department(BoundedVec(name.as_bytes().to_vec()), department_type),
+        );
+
+        view! { <SignTransactionFn tx=tx /> }
     });
 
-    let async_result = move || {
-        transaction_resource
-            .get()
-            .as_deref()
-            .map(|_| view! { <div></div> }.into_any())
-            // This loading state will only show before the first load
-            .unwrap_or_else(|| {
-                view! {
-                    <div class="alert">
-                        <span class="loading loading-spinner"></span>
-                        "Loading... Please sign with extension."
-                    </div>
-                }
-                .into_any()
-            })
-    };
-
-    let error_fn = move || {
-        if !error().is_empty() {
-            view! {
-                <div role="alert" class="alert alert-error">
-                    {move || error()}
-                </div>
-            }
-            .into_any()
-        } else {
-            view! { <div></div> }.into_any()
-        }
-    };
-
-    let extrinsic_success_fn = move || {
-        if !extrinsic_success().is_empty() {
-            view! {
-                <div role="alert" class="alert alert-success">
-                    {move || extrinsic_success()}
-                </div>
-            }
-            .into_any()
-        } else {
-            view! { <div></div> }.into_any()
-        }
-    };
-
-    view! {
-        <div class="md:container md:mx-auto">
-            <div>{async_result}</div>
-            <br />
-            <div>{move || error_fn()}</div>
-            <br />
-            <div>{move || extrinsic_success_fn()}</div>
-
-        </div>
-    }
+    view! { <AppErrorBoundary>{sign_transaction}</AppErrorBoundary> }
 }
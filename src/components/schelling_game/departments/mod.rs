@@ -1,6 +1,8 @@
 pub mod add_member_to_department;
+pub mod add_member_to_department_params;
 pub mod add_member_to_department_sign_in;
 pub mod create_department;
 pub mod create_department_sign_in;
 pub mod remove_member_from_department;
+pub mod remove_member_from_department_params;
 pub mod remove_member_from_department_sign_in;
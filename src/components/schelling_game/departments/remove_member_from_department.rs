@@ -6,7 +6,6 @@ use leptos::prelude::*;
 
 #[component]
 pub fn RemoveMemberFromDepartment(department_id: u64) -> impl IntoView {
-    // gloo::console::log!(user_to_calculate());
     let (current_view, set_current_view) = signal(View::Form);
 
     let (account_id, set_account_id) = signal(String::from(""));
@@ -22,7 +21,7 @@ pub fn RemoveMemberFromDepartment(department_id: u64) -> impl IntoView {
                 <div class="container mx-auto px-10">
                     <form
 
-                        id="add-department-member-submit-from"
+                        id="remove-department-member-submit-from"
                         on:submit=submit_click
                     >
                         <div class="mb-5">
@@ -0,0 +1,25 @@
+use crate::components::schelling_game::departments::remove_member_from_department::RemoveMemberFromDepartment;
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+
+#[component]
+pub fn RemoveMemberFromDepartmentParams() -> impl IntoView {
+    let params = use_params_map();
+
+    let department_id = move || {
+        params.with(|params| {
+            params
+                .get("department_id")
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or_default()
+        })
+    };
+
+    let params_value = untrack(move || department_id());
+
+    view! {
+        <div>
+            <RemoveMemberFromDepartment department_id=params_value />
+        </div>
+    }
+}
@@ -1 +1,6 @@
+pub mod common;
+pub mod department_funding;
+pub mod departments;
 pub mod positive_externality;
+pub mod profile_validation;
+pub mod project_tips;
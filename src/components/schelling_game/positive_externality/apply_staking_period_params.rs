@@ -1,4 +1,5 @@
-use crate::components::schelling_game::positive_externality::apply_staking_period::ApplyStakingPeriod;
+use crate::components::schelling_game::common::apply_staking_period::ApplyStakingPeriod;
+use crate::components::schelling_game::common::games::PositiveExternalityGame;
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
 
@@ -13,7 +14,7 @@ pub fn ApplyStakingPeriodParams() -> impl IntoView {
 
     view! {
         <div>
-            <ApplyStakingPeriod user_to_calculate=params_value />
+            <ApplyStakingPeriod<PositiveExternalityGame> id=params_value />
         </div>
     }
 }
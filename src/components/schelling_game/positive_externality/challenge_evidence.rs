@@ -2,8 +2,9 @@ use crate::components::api::ipfs_request::ipfs_call_json_string;
 use crate::components::api::select_ipfs_provider::DEFAULT_IPFS_PROVIDER;
 use crate::components::markdown::markdown_field::MarkdownField;
 use crate::components::schelling_game::positive_externality::challenge_evidence_sign_in::SignTransaction;
-use crate::components::schelling_game::positive_externality::rpc::evidence_end_block::EvidenceEndBlock;
-use crate::components::schelling_game::positive_externality::storage::challenger_fees::ChallengerFees;
+use crate::components::schelling_game::common::challenger_fees::ChallengerFees;
+use crate::components::schelling_game::common::games::PositiveExternalityGame;
+use crate::components::schelling_game::common::period_end_block::{PeriodEnd, PeriodEndBlock};
 use crate::services::common_imp::View;
 use json::object;
 use leptos::ev::SubmitEvent;
@@ -64,8 +65,11 @@ pub fn ChallengeEvidence(user_to_calculate: String) -> impl IntoView {
             view! {
                 <div class="max-w-5xl mx-auto max-md:mx-10">
                     <div class="text-gray-900 dark:text-white">
-                        <EvidenceEndBlock user_to_calculate=user_to_calculate.clone() />
-                        <ChallengerFees user_to_calculate=user_to_calculate.clone() />
+                        <PeriodEndBlock<PositiveExternalityGame>
+                            id=user_to_calculate.clone()
+                            period=PeriodEnd::Evidence
+                        />
+                        <ChallengerFees<PositiveExternalityGame> />
                     </div>
                     <form id="challenge-evidence-submit-from" on:submit=submit_click>
 
@@ -1,11 +1,17 @@
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::components::navigation::nav::Nav;
-use crate::components::schelling_game::positive_externality::apply_jurors::ApplyJurors;
-
-use crate::components::schelling_game::positive_externality::commit_vote::CommitVote;
-use crate::components::schelling_game::positive_externality::draw_jurors::DrawJurors;
-use crate::components::schelling_game::positive_externality::reveal_vote::RevealVote;
-use crate::components::schelling_game::positive_externality::storage::get_period_fn::get_period_fn;
+use crate::components::schelling_game::common::apply_jurors::ApplyJurors;
+use crate::components::schelling_game::common::commit_vote::CommitVote;
+use crate::components::schelling_game::common::draw_jurors::DrawJurors;
+use crate::components::schelling_game::common::games::PositiveExternalityGame;
+use crate::components::schelling_game::common::game_timeline::GameTimeline;
+use crate::components::schelling_game::common::get_period_fn::get_period_fn;
+use crate::components::schelling_game::common::has_user_staked::HasUserStaked;
+use crate::components::schelling_game::common::reveal_vote::RevealVote;
+use crate::components::schelling_game::common::score_results::ScoreResults;
+use crate::components::schelling_game::common::user_staked_value::UserStakedValue;
 use crate::services::common_services::polkadot::runtime_types::pallet_schelling_game_shared::types::Period;
+use crate::services::error::AppError;
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
 
@@ -31,38 +37,48 @@ pub fn SchellingGame() -> impl IntoView {
 pub fn SchellingGameComponent(user_to_calculate: String) -> impl IntoView {
     let user_to_calculate = move || user_to_calculate.clone();
 
-    let period = get_period_fn(user_to_calculate());
+    let period = get_period_fn::<PositiveExternalityGame>(user_to_calculate());
 
-    let myview = move || {
+    let myview = move || -> Result<AnyView, AppError> {
         {
             {
                 // let period_read_signal = period();
-                if let Some(period) = period() {
+                let Some(result) = period.get() else {
+                    return Ok(view! { <div></div> }.into_any());
+                };
+                let period = result?;
+                if let Some(period) = period {
                     let view = match period {
                         Period::Evidence => view! { <div></div> }.into_any(),
                         Period::Staking => {
                             view! {
                                 <div>
-                                    <ApplyJurors user_to_calculate=user_to_calculate() />
+                                    <ApplyJurors<PositiveExternalityGame> id=user_to_calculate() />
+                                    <HasUserStaked<PositiveExternalityGame> id=user_to_calculate() />
+                                    <UserStakedValue<PositiveExternalityGame> id=user_to_calculate() />
                                 </div>
                             }.into_any()
                         }
                         Period::Drawing => view! {
                             <div>
-                                <DrawJurors user_to_calculate=user_to_calculate() />
+                                <DrawJurors<PositiveExternalityGame> id=user_to_calculate() />
                             </div>
                         }.into_any(),
                         Period::Commit => view! {
                             <div>
-                                <CommitVote user_to_calculate=user_to_calculate() />
+                                <CommitVote<PositiveExternalityGame> id=user_to_calculate() />
                             </div>
                         }.into_any(),
                         Period::Vote => view! {
                             <div>
-                                <RevealVote user_to_calculate=user_to_calculate() />
+                                <RevealVote<PositiveExternalityGame> id=user_to_calculate() />
+                            </div>
+                        }.into_any(),
+                        Period::Appeal => view! {
+                            <div>
+                                <ScoreResults<PositiveExternalityGame> id=user_to_calculate() />
                             </div>
                         }.into_any(),
-                        Period::Appeal => view! { <div></div> }.into_any(),
                         Period::Execution => {
                             view! {
                                 <div>
@@ -70,21 +86,22 @@ pub fn SchellingGameComponent(user_to_calculate: String) -> impl IntoView {
                                         You are in Execution phase. Get your incentives
                                     </div>
 
+                                    <ScoreResults<PositiveExternalityGame> id=user_to_calculate() />
                                     <ExecutionLinks user_to_calculate=user_to_calculate() />
 
                                 </div>
                             }
                         }.into_any()
                     };
-                    view
+                    Ok(view)
                 } else {
-                    view! {
+                    Ok(view! {
                         <div class="container mx-auto">
-                            <p class="text-gray-900 dark:text-white">{format!("{:?}", period())}</p>
+                            <p class="text-gray-900 dark:text-white">{format!("{:?}", period)}</p>
                             <p class="text-gray-900 dark:text-white">{"No period"}</p>
                         </div>
                     }
-                    .into_any()
+                    .into_any())
                 }
             }
         }
@@ -93,9 +110,12 @@ pub fn SchellingGameComponent(user_to_calculate: String) -> impl IntoView {
     view! {
         <div>
             <Nav />
+            <GameTimeline<PositiveExternalityGame> id=user_to_calculate() />
             // {move || account()}
             // {move || format!("{:?}", period())}
-            {move || myview()}
+            <AppErrorBoundary on_retry=move |_: ()| period.refetch()>
+                {move || myview()}
+            </AppErrorBoundary>
         </div>
     }
 }
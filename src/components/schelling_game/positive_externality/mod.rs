@@ -1,28 +1,12 @@
 // pub mod add_incentives_count;
 // pub mod add_incentives_count_sign_in;
-pub mod apply_jurors;
-pub mod apply_jurors_sign_in;
-pub mod apply_staking_period;
 pub mod apply_staking_period_params;
-pub mod apply_staking_period_sign_in;
-pub mod change_period;
-pub mod change_period_sign_in;
-pub mod commit_vote;
-pub mod commit_vote_sign_in;
 pub mod create_post;
 pub mod create_post_sign_in;
-pub mod draw_jurors;
-pub mod draw_jurors_sign_in;
 pub mod game;
 pub mod home;
 pub mod release_juror_incentives;
 pub mod release_juror_incentives_sign_in;
 // pub mod release_positive_externality_fund;
 // pub mod release_positive_externality_fund_sign_in;
-pub mod reveal_vote;
-pub mod reveal_vote_sign_in;
-pub mod rpc;
-pub mod storage;
-pub mod unstaking;
-pub mod unstaking_sign_in;
 pub mod views;
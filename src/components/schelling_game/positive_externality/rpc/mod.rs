@@ -1,8 +0,0 @@
-pub mod commit_end_block;
-pub mod drawing_period_end;
-pub mod evidence_end_block;
-// pub mod has_user_staked;
-pub mod juror_selected;
-pub mod staking_end_block;
-// pub mod user_staked_value;
-pub mod vote_end_block;
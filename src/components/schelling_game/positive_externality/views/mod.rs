@@ -1,6 +1,6 @@
 pub mod execution_links;
 pub mod juror_selected_check;
-// pub mod validation_list;
+pub mod validation_list;
 pub mod view_positive_externality;
 pub mod view_positive_externality_all_posts;
 pub mod view_positive_externality_latest;
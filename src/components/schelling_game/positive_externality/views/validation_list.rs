@@ -1,17 +1,15 @@
-use crate::components::login::get_login_account::AccountState;
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::components::navigation::nav::Nav;
-use crate::components::schelling_game::positive_externality::rpc::has_user_staked::HasUserStaked;
-use crate::components::schelling_game::positive_externality::rpc::user_staked_value::UserStakedValue;
-use crate::components::schelling_game::positive_externality::storage::get_period::GetPeriod;
-use crate::constants::constant::NODE_URL;
-use codee::string::JsonSerdeCodec;
-use jsonrpsee_core::{client::ClientT, rpc_params};
-use jsonrpsee_wasm_client::WasmClientBuilder;
+use crate::components::schelling_game::common::games::PositiveExternalityGame;
+use crate::components::schelling_game::common::get_period::GetPeriod;
+use crate::components::schelling_game::common::has_user_staked::HasUserStaked;
+use crate::components::schelling_game::common::user_staked_value::UserStakedValue;
+use crate::services::chain_client::ChainClient;
+use crate::services::error::AppError;
 use leptos::ev::SubmitEvent;
 use leptos::html;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use leptos_use::storage::use_local_storage;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -23,14 +21,15 @@ struct PaginatedPosts {
 
 #[component]
 pub fn ValidationList() -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
     let (page, set_page) = signal(1);
     let (page_size, set_page_size) = signal(10);
     let (accounts, set_accounts) = signal::<Option<Vec<String>>>(None);
     let (total_posts_length, set_total_posts_length) = signal(0);
     let (total_pages, set_total_pages) = signal(0);
+    let (load_error, set_load_error) = signal::<Option<AppError>>(None);
+    let (reload, set_reload) = signal(0u32);
     let input_element_page: NodeRef<html::Input> = NodeRef::new();
-    let (account_state, set_account_state, reset_account) =
-        use_local_storage::<AccountState, JsonSerdeCodec>("account-state");
 
     let input_element_page_size: NodeRef<html::Input> = NodeRef::new();
 
@@ -38,16 +37,25 @@ pub fn ValidationList() -> impl IntoView {
     Effect::new(move |_| {
         let page = page();
         let page_size = page_size();
+        reload.track();
 
         spawn_local(async move {
-            let result = validation_list_length(page, page_size).await;
+            let result = validation_list_length(chain_client, page, page_size).await;
             match result {
                 Ok((Some(posts), total_length)) => {
+                    set_load_error.set(None);
                     set_accounts.set(Some(posts.clone()));
                     set_total_posts_length.set(total_length);
                     set_total_pages.set((total_length + page_size - 1) / page_size);
                 }
-                _ => {
+                Ok((None, _)) => {
+                    set_load_error.set(None);
+                    set_accounts.set(None);
+                    set_total_posts_length.set(0);
+                    set_total_pages.set(0);
+                }
+                Err(e) => {
+                    set_load_error.set(Some(e));
                     set_accounts.set(None);
                     set_total_posts_length.set(0);
                     set_total_pages.set(0);
@@ -95,6 +103,10 @@ pub fn ValidationList() -> impl IntoView {
                     Validation List
                 </h1>
 
+                <AppErrorBoundary on_retry=move |_: ()| set_reload.update(|n| *n += 1)>
+                    {move || load_error().map_or(Ok(()), Err)}
+                </AppErrorBoundary>
+
                 // Display posts
                 <div class="space-y-2">
                     {move || match accounts() {
@@ -121,16 +133,10 @@ pub fn ValidationList() -> impl IntoView {
                                                 class="bg-green-100 border border-green-400 text-green-700 px-4 py-3 rounded"
                                                 role="alert"
                                             >
-                                                <GetPeriod user_to_calculate=account.clone() />
+                                                <GetPeriod<PositiveExternalityGame> id=account.clone() />
                                             </div>
-                                            <HasUserStaked
-                                                user_to_calculate=account.clone()
-                                                account_state=account_state
-                                            />
-                                            <UserStakedValue
-                                                user_to_calculate=account.clone()
-                                                account_state=account_state
-                                            />
+                                            <HasUserStaked<PositiveExternalityGame> id=account.clone() />
+                                            <UserStakedValue<PositiveExternalityGame> id=account.clone() />
                                         </>
                                     }
                                 })
@@ -231,24 +237,16 @@ pub fn ValidationList() -> impl IntoView {
 
 // Mock API function (replace with your actual API call)
 async fn validation_list_length(
+    chain_client: ChainClient,
     page: u64,
     page_size: u64,
-) -> Result<(Option<Vec<String>>, u64), String> {
-    let client = WasmClientBuilder::default().build(NODE_URL).await.unwrap();
-    let validation_list_length: u64 = client
-        .request("positiveexternality_validationlistlength", rpc_params![])
-        .await
-        .unwrap();
+) -> Result<(Option<Vec<String>>, u64), AppError> {
+    let rpc = chain_client.shivarthu_rpc().await?;
+    let validation_list_length = rpc.validation_list_length().await?;
 
     gloo::console::log!("allpost", validation_list_length.clone());
 
-    let accounts: Option<Vec<String>> = client
-        .request(
-            "positiveexternality_validationlist_latest",
-            rpc_params![page, page_size],
-        )
-        .await
-        .unwrap();
+    let accounts = rpc.validation_list_latest(page, page_size).await?;
 
     gloo::console::log!("accounts", accounts.clone());
 
@@ -1,11 +1,11 @@
 use leptos::prelude::*;
-use crate::constants::constant::NODE_URL;
+use crate::services::chain_client::ChainClient;
+use crate::services::error::AppError;
+use crate::components::common::error_view::AppErrorBoundary;
 use leptos::ev::SubmitEvent;
 use serde::{Deserialize, Serialize};
 use leptos::task::spawn_local;
 use leptos::html;
-use jsonrpsee_core::{client::ClientT, rpc_params};
-use jsonrpsee_wasm_client::WasmClientBuilder;
 use crate::components::schelling_game::positive_externality::views::view_post_positive_externality::ViewPostPositiveExternality;
 use crate::components::navigation::nav::Nav;
 
@@ -18,11 +18,14 @@ struct PaginatedPosts {
 
 #[component]
 pub fn ViewPositiveExternalityAllPosts() -> impl IntoView {
+    let chain_client = expect_context::<ChainClient>();
     let (page, set_page) = signal(1);
     let (page_size, set_page_size) = signal(10);
     let (posts, set_posts) = signal::<Option<Vec<u64>>>(None);
     let (total_posts_length, set_total_posts_length) = signal(0);
     let (total_pages, set_total_pages) = signal(0);
+    let (load_error, set_load_error) = signal::<Option<AppError>>(None);
+    let (reload, set_reload) = signal(0u32);
     let input_element_page: NodeRef<html::Input> = NodeRef::new();
 
     let input_element_page_size: NodeRef<html::Input> = NodeRef::new();
@@ -31,16 +34,25 @@ pub fn ViewPositiveExternalityAllPosts() -> impl IntoView {
     Effect::new(move |_| {
         let page = page();
         let page_size = page_size();
+        reload.track();
 
         spawn_local(async move {
-            let result = paginate_posts(page, page_size).await;
+            let result = paginate_posts(chain_client, page, page_size).await;
             match result {
                 Ok((Some(posts), total_length)) => {
+                    set_load_error.set(None);
                     set_posts.set(Some(posts.clone()));
                     set_total_posts_length.set(total_length);
                     set_total_pages.set((total_length + page_size - 1) / page_size);
                 }
-                _ => {
+                Ok((None, _)) => {
+                    set_load_error.set(None);
+                    set_posts.set(None);
+                    set_total_posts_length.set(0);
+                    set_total_pages.set(0);
+                }
+                Err(e) => {
+                    set_load_error.set(Some(e));
                     set_posts.set(None);
                     set_total_posts_length.set(0);
                     set_total_pages.set(0);
@@ -89,6 +101,10 @@ pub fn ViewPositiveExternalityAllPosts() -> impl IntoView {
                     Posts
                 </h1>
 
+                <AppErrorBoundary on_retry=move |_: ()| set_reload.update(|n| *n += 1)>
+                    {move || load_error().map_or(Ok(()), Err)}
+                </AppErrorBoundary>
+
                 // Display posts
                 <div class="space-y-2">
                     {move || match posts() {
@@ -197,22 +213,17 @@ pub fn ViewPositiveExternalityAllPosts() -> impl IntoView {
 }
 
 // Mock API function (replace with your actual API call)
-async fn paginate_posts(page: u64, page_size: u64) -> Result<(Option<Vec<u64>>, u64), String> {
-    let client = WasmClientBuilder::default().build(NODE_URL).await.unwrap();
-    let all_posts_length: u64 = client
-        .request("all_postlength", rpc_params![])
-        .await
-        .unwrap();
+async fn paginate_posts(
+    chain_client: ChainClient,
+    page: u64,
+    page_size: u64,
+) -> Result<(Option<Vec<u64>>, u64), AppError> {
+    let rpc = chain_client.shivarthu_rpc().await?;
+    let all_posts_length = rpc.all_posts_length().await?;
 
     gloo::console::log!("allposte", all_posts_length.clone());
 
-    let posts: Option<Vec<u64>> = client
-        .request(
-            "positiveexternality_paginateall_posts",
-            rpc_params![page, page_size],
-        )
-        .await
-        .unwrap();
+    let posts = rpc.paginate_all_posts(page, page_size).await?;
 
     gloo::console::log!("posts", posts.clone());
 
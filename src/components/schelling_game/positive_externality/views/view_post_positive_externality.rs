@@ -1,63 +1,67 @@
 use crate::components::api::ipfs_fetch_response::ipfs_fetch_response;
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::constants::constant::IPFSFetchProvider;
 use crate::constants::constant::DEFAULT_IPFS_FETCH_PROVIDER;
-use crate::constants::constant::NODE_URL;
+use crate::services::chain_client::ChainClient;
 use crate::services::common_services::polkadot;
+use crate::services::error::AppError;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use polkadot::runtime_types::pallet_support::Content;
 use serde::{Deserialize, Serialize};
-use subxt::{OnlineClient, PolkadotConfig};
 
 #[component]
 pub fn ViewPostPositiveExternality(id: u64) -> impl IntoView {
-    let (data_post, set_data_post) = signal("".to_string());
+    let chain_client = expect_context::<ChainClient>();
+    let (data_post, set_data_post) = signal::<Option<Result<String, AppError>>>(None);
+    let (reload, set_reload) = signal(0u32);
 
     Effect::new(move |_| {
+        reload.track();
         spawn_local(async move {
-            let data = get_post_data(id).await;
+            let data = get_post_data(chain_client, id).await;
 
-            gloo::console::log!(data.clone(), "In data");
+            gloo::console::log!(format!("{:?}", data), "In data");
 
-            set_data_post(data);
+            set_data_post(Some(data));
         });
     });
-    view! { <div class="dark:text-white text-gray-800">{data_post}</div> }
+    view! {
+        <div class="dark:text-white text-gray-800">
+            <AppErrorBoundary on_retry=move |_: ()| set_reload.update(|n| *n += 1)>
+                {move || data_post().unwrap_or_else(|| Ok(String::new()))}
+            </AppErrorBoundary>
+        </div>
+    }
 }
 
-async fn get_post_data(id: u64) -> String {
-    let client = OnlineClient::<PolkadotConfig>::from_url(NODE_URL)
-        .await
-        .unwrap();
+async fn get_post_data(chain_client: ChainClient, id: u64) -> Result<String, AppError> {
+    let client = chain_client.online().await?;
 
     let post_storage = polkadot::storage().positive_externality().post_by_id(id);
 
-    let value = client
+    let post = client
         .storage()
         .at_latest()
-        .await
-        .unwrap()
+        .await?
         .fetch(&post_storage)
-        .await
-        .unwrap();
-
-    let post = value.unwrap();
-
-    let content = post.content;
-    let mut resp_option: Option<PositiveExternalityResponse> = None;
+        .await?
+        .ok_or_else(|| AppError::Validation(format!("Post {} does not exist", id)))?;
 
-    if let Content::IPFS(ipfsdata) = content {
-        let ipfs_hash = String::from_utf8(ipfsdata).unwrap();
-        gloo::console::log!("ipfs_hash", ipfs_hash.clone());
+    match post.content {
+        Content::IPFS(ipfsdata) => {
+            let ipfs_hash =
+                String::from_utf8(ipfsdata).map_err(|e| AppError::Decode(e.to_string()))?;
+            gloo::console::log!("ipfs_hash", ipfs_hash.clone());
 
-        let resp = ipfs_fetch(&ipfs_hash, DEFAULT_IPFS_FETCH_PROVIDER).await;
-        resp_option = Some(resp.clone());
+            let resp = ipfs_fetch(&ipfs_hash, DEFAULT_IPFS_FETCH_PROVIDER).await?;
+            Ok(resp.details)
+        }
+        _ => Err(AppError::Validation(format!(
+            "Post {} has no IPFS content",
+            id
+        ))),
     }
-
-    let text = resp_option.unwrap().details;
-
-    text
-    // "hello2".to_string()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,9 +73,9 @@ pub struct PositiveExternalityResponse {
 pub async fn ipfs_fetch(
     hash: &str,
     ipfs_fetch_provider: IPFSFetchProvider<'_>,
-) -> PositiveExternalityResponse {
-    let resp = ipfs_fetch_response(hash, &ipfs_fetch_provider.address).await;
-    let body = resp.json::<PositiveExternalityResponse>().await.unwrap();
+) -> Result<PositiveExternalityResponse, AppError> {
+    let resp = ipfs_fetch_response(hash, &ipfs_fetch_provider.address).await?;
+    let body = resp.json::<PositiveExternalityResponse>().await?;
     // log!(body.name);
-    body
+    Ok(body)
 }
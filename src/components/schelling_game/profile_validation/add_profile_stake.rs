@@ -1,11 +1,13 @@
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::components::navigation::nav::Nav;
+use crate::components::schelling_game::common::games::ProfileValidationGame;
+use crate::components::schelling_game::common::total_fund_collected::TotalFundCollected;
 use crate::components::schelling_game::profile_validation::add_profile_stake_sign_in::SignTransaction;
 use crate::services::common_imp::View;
-use crate::services::error::ErrorString;
+use crate::services::error::AppError;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
-use crate::components::schelling_game::profile_validation::storage::get_total_fund_for_profile_collected::TotalFundProfileCollected;
 
 #[component]
 pub fn AddProfileStake() -> impl IntoView {
@@ -15,19 +17,22 @@ pub fn AddProfileStake() -> impl IntoView {
 
     let (current_view, set_current_view) = signal(View::Form);
 
-    let (profile_stake, set_profile_stake) = signal::<Result<u128, ErrorString>>(Ok(0));
+    let (profile_stake, set_profile_stake) = signal::<Result<u128, AppError>>(Ok(0));
 
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
-
-        set_current_view(View::Success);
+        if profile_stake().is_ok() {
+            set_current_view(View::Success);
+        }
     };
 
     let stake_value = move |value: String| {
-        let stake = value.parse::<u128>().expect("Invalid input");
-        gloo::console::log!(stake);
+        let stake = value
+            .parse::<u128>()
+            .map_err(|e| AppError::Validation(format!("Invalid profile stake: {e}")));
+        gloo::console::log!(format!("{:?}", stake));
 
-        set_profile_stake(Ok(stake));
+        set_profile_stake(stake);
     };
 
     let render_view = move || {
@@ -36,7 +41,7 @@ pub fn AddProfileStake() -> impl IntoView {
             view! {
                 <div class="container mx-auto px-10">
                     <div>
-                        <TotalFundProfileCollected profile_user_account=profile_user_account() />
+                        <TotalFundCollected<ProfileValidationGame> id=profile_user_account() />
                     </div>
                     <br />
                     <form id="profile-stake-submit-from" on:submit=submit_click>
@@ -63,6 +68,8 @@ pub fn AddProfileStake() -> impl IntoView {
                             Submit
                         </button>
                     </form>
+                    <br />
+                    <AppErrorBoundary>{move || profile_stake().map(|_| ())}</AppErrorBoundary>
                 </div>
             }.into_any()
         }
@@ -70,7 +77,7 @@ pub fn AddProfileStake() -> impl IntoView {
             view! {
                 <div>
                     <SignTransaction
-                        stake=profile_stake().unwrap()
+                        stake=profile_stake().unwrap_or_default()
                         profile_user_account=profile_user_account()
                     />
 
@@ -1,9 +1,10 @@
 use crate::components::api::ipfs_request::ipfs_call_json_string;
 use crate::components::api::select_ipfs_provider::DEFAULT_IPFS_PROVIDER;
 use crate::components::markdown::markdown_field::MarkdownField;
+use crate::components::schelling_game::common::challenger_fees::ChallengerFees;
+use crate::components::schelling_game::common::games::ProfileValidationGame;
+use crate::components::schelling_game::common::period_end_block::{PeriodEnd, PeriodEndBlock};
 use crate::components::schelling_game::profile_validation::challenge_evidence_sign_in::SignTransaction;
-use crate::components::schelling_game::profile_validation::rpc::evidence_end_block::EvidenceEndBlock;
-use crate::components::schelling_game::profile_validation::storage::challenger_fees::ChallengerFees;
 use crate::services::common_imp::View;
 use json::object;
 use leptos::ev::SubmitEvent;
@@ -45,7 +46,7 @@ pub fn ChallengeEvidence(profile_user_account: String) -> impl IntoView {
                 async move { get_cid_post(details, set_current_view, set_post_cid).await }
             },
         );
-    let submitted = submit_action.input();
+    let _submitted = submit_action.input();
     let pending = submit_action.pending();
     let submit_action_value = submit_action.value();
 
@@ -65,8 +66,11 @@ pub fn ChallengeEvidence(profile_user_account: String) -> impl IntoView {
             view! {
                 <div class="max-w-5xl mx-auto max-md:mx-10">
                     <div class="text-gray-900 dark:text-white">
-                        <EvidenceEndBlock profile_user_account=profile_user_account.clone() />
-                        <ChallengerFees profile_user_account=profile_user_account.clone() />
+                        <PeriodEndBlock<ProfileValidationGame>
+                            id=profile_user_account.clone()
+                            period=PeriodEnd::Evidence
+                        />
+                        <ChallengerFees<ProfileValidationGame> />
                     </div>
                     <form id="challenge-evidence-submit-from" on:submit=submit_click>
 
@@ -1,5 +1,6 @@
 use crate::components::api::ipfs_fetch_response::ipfs_fetch_response;
 use crate::constants::constant::IPFSFetchProvider;
+use crate::services::error::AppError;
 
 use serde::{Deserialize, Serialize};
 
@@ -14,9 +15,9 @@ pub struct ProfileFetchResponse {
 pub async fn ipfs_fetch(
     hash: &str,
     ipfs_fetch_provider: IPFSFetchProvider<'_>,
-) -> ProfileFetchResponse {
-    let resp = ipfs_fetch_response(hash, &ipfs_fetch_provider.address).await;
-    let body = resp.json::<ProfileFetchResponse>().await.unwrap();
+) -> Result<ProfileFetchResponse, AppError> {
+    let resp = ipfs_fetch_response(hash, &ipfs_fetch_provider.address).await?;
+    let body = resp.json::<ProfileFetchResponse>().await?;
     // log!(body.name);
-    body
+    Ok(body)
 }
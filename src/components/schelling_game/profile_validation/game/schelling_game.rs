@@ -1,13 +1,17 @@
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::components::navigation::nav::Nav;
-use crate::components::schelling_game::profile_validation::apply_jurors::ApplyJurors;
-
+use crate::components::schelling_game::common::apply_jurors::ApplyJurors;
+use crate::components::schelling_game::common::commit_vote::CommitVote;
+use crate::components::schelling_game::common::draw_jurors::DrawJurors;
+use crate::components::schelling_game::common::games::ProfileValidationGame;
+use crate::components::schelling_game::common::get_incentives::GetIncentives;
+use crate::components::schelling_game::common::game_timeline::GameTimeline;
+use crate::components::schelling_game::common::get_period_fn::get_period_fn;
+use crate::components::schelling_game::common::reveal_vote::RevealVote;
+use crate::components::schelling_game::common::unstaking::Unstaking;
 use crate::components::schelling_game::profile_validation::challenge_evidence::ChallengeEvidence;
-
-use crate::components::schelling_game::profile_validation::commit_vote::CommitVote;
-use crate::components::schelling_game::profile_validation::draw_jurors::DrawJurors;
-use crate::components::schelling_game::profile_validation::reveal_vote::RevealVote;
-use crate::components::schelling_game::profile_validation::storage::get_period_fn::get_period_fn;
 use crate::services::common_services::polkadot::runtime_types::pallet_schelling_game_shared::types::Period;
+use crate::services::error::AppError;
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
 
@@ -31,13 +35,16 @@ pub fn SchellingGame() -> impl IntoView {
 pub fn SchellingGameComponent(profile_user_account: String) -> impl IntoView {
     let profile_user_account = move || profile_user_account.clone();
 
-    let period = get_period_fn(profile_user_account());
+    let period = get_period_fn::<ProfileValidationGame>(profile_user_account());
 
-    let myview = move || {
+    let myview = move || -> Result<AnyView, AppError> {
         {
             {
-                // let period_read_signal = period();
-                if let Some(period) = period() {
+                let Some(result) = period.get() else {
+                    return Ok(view! { <div></div> }.into_any());
+                };
+                let period = result?;
+                if let Some(period) = period {
                     let view = match period {
                         Period::Evidence => view! {
                             <div>
@@ -49,23 +56,23 @@ pub fn SchellingGameComponent(profile_user_account: String) -> impl IntoView {
                         Period::Staking => {
                             view! {
                                 <div>
-                                    <ApplyJurors profile_user_account=profile_user_account() />
+                                    <ApplyJurors<ProfileValidationGame> id=profile_user_account() />
                                 </div>
                             }.into_any()
                         }
                         Period::Drawing => view! {
                             <div>
-                                <DrawJurors profile_user_account=profile_user_account() />
+                                <DrawJurors<ProfileValidationGame> id=profile_user_account() />
                             </div>
                         }.into_any(),
                         Period::Commit => view! {
                             <div>
-                                <CommitVote profile_user_account=profile_user_account() />
+                                <CommitVote<ProfileValidationGame> id=profile_user_account() />
                             </div>
                         }.into_any(),
                         Period::Vote => view! {
                             <div>
-                                <RevealVote profile_user_account=profile_user_account() />
+                                <RevealVote<ProfileValidationGame> id=profile_user_account() />
                             </div>
                         }.into_any(),
                         Period::Appeal => view! { <div></div> }.into_any(),
@@ -75,20 +82,24 @@ pub fn SchellingGameComponent(profile_user_account: String) -> impl IntoView {
                                     <div class="bg-green-100 border border-green-400 text-green-700 px-4 py-3 rounded border text-[10px] sm:text-base">
                                         You are in Execution phase. Get your incentives
                                     </div>
+                                    <br />
+                                    <GetIncentives<ProfileValidationGame> id=profile_user_account() />
+                                    <br />
+                                    <Unstaking<ProfileValidationGame> id=profile_user_account() />
 
                                 </div>
                             }
                         }.into_any()
                     };
-                    view
+                    Ok(view)
                 } else {
-                    view! {
+                    Ok(view! {
                         <div class="container mx-auto">
-                            <p class="text-gray-900 dark:text-white">{format!("{:?}", period())}</p>
+                            <p class="text-gray-900 dark:text-white">{format!("{:?}", period)}</p>
                             <p class="text-gray-900 dark:text-white">{"No period"}</p>
                         </div>
                     }
-                    .into_any()
+                    .into_any())
                 }
             }
         }
@@ -97,9 +108,10 @@ pub fn SchellingGameComponent(profile_user_account: String) -> impl IntoView {
     view! {
         <div>
             <Nav />
-            // {move || account()}
-            // {move || format!("{:?}", period())}
-            {move || myview()}
+            <GameTimeline<ProfileValidationGame> id=profile_user_account() />
+            <AppErrorBoundary on_retry=move |_: ()| period.refetch()>
+                {move || myview()}
+            </AppErrorBoundary>
         </div>
     }
 }
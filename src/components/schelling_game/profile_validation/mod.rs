@@ -2,26 +2,10 @@ pub mod add_profile;
 pub mod add_profile_sign_in;
 pub mod add_profile_stake;
 pub mod add_profile_stake_sign_in;
-pub mod apply_jurors;
-pub mod apply_jurors_sign_in;
 pub mod challenge_evidence;
 pub mod challenge_evidence_sign_in;
-pub mod change_period;
-pub mod change_period_sign_in;
-pub mod commit_vote;
-pub mod commit_vote_sign_in;
-pub mod draw_jurors;
-pub mod draw_jurors_sign_in;
 pub mod fetch_ipfs_profile;
 pub mod game;
-// pub mod get_incentives;
-// pub mod get_incentives_sign_in;
-pub mod reveal_vote;
-pub mod reveal_vote_sign_in;
-pub mod rpc;
-pub mod storage;
-pub mod unstaking;
-pub mod unstaking_sign_in;
 pub mod view_profile;
 pub mod view_profile_from_address;
 pub mod views;
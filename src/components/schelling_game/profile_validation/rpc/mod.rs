@@ -1,6 +0,0 @@
-pub mod commit_end_block;
-pub mod drawing_period_end;
-pub mod evidence_end_block;
-pub mod juror_selected;
-pub mod staking_end_block;
-pub mod vote_end_block;
@@ -1,4 +0,0 @@
-pub mod challenger_fees;
-pub mod get_period;
-pub mod get_period_fn;
-pub mod get_total_fund_for_profile_collected;
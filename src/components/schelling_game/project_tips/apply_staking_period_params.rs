@@ -1,4 +1,5 @@
-use crate::components::schelling_game::project_tips::apply_staking_period::ApplyStakingPeriod;
+use crate::components::schelling_game::common::apply_staking_period::ApplyStakingPeriod;
+use crate::components::schelling_game::common::games::ProjectTipsGame;
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
 
@@ -19,7 +20,7 @@ pub fn ApplyStakingPeriodParams() -> impl IntoView {
 
     view! {
         <div>
-            <ApplyStakingPeriod project_id=params_value />
+            <ApplyStakingPeriod<ProjectTipsGame> id=params_value />
         </div>
     }
 }
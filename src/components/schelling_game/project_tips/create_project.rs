@@ -1,9 +1,11 @@
 use crate::components::api::ipfs_request::ipfs_call_json_string;
 use crate::components::api::select_ipfs_provider::DEFAULT_IPFS_PROVIDER;
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::components::markdown::markdown_field::MarkdownField;
 use crate::components::navigation::nav::Nav;
 use crate::components::schelling_game::project_tips::create_project_sign_in::SignTransaction;
 use crate::services::common_imp::View;
+use crate::services::error::AppError;
 use json::object;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
@@ -61,7 +63,7 @@ pub fn CreateProject() -> impl IntoView {
     let (markdown, set_markdown) = signal(String::from(""));
     let (post_cid, set_post_cid) = signal(String::from(""));
     let (tip_name, set_tip_name) = signal(String::from(""));
-    let (funding_needed, set_funding_needed) = signal::<Option<u128>>(None);
+    let (funding_needed, set_funding_needed) = signal::<Result<u128, AppError>>(Ok(0));
 
     let submit_action: Action<(String, WriteSignal<View>, WriteSignal<String>), (), LocalStorage> =
         Action::new_unsync(
@@ -83,13 +85,17 @@ pub fn CreateProject() -> impl IntoView {
 
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
-        submit_action.dispatch((markdown(), set_current_view, set_post_cid));
+        if funding_needed().is_ok() {
+            submit_action.dispatch((markdown(), set_current_view, set_post_cid));
+        }
     };
 
     let funding_needed_changed = move |value: String| {
-        let choice_value = value.parse::<u128>().expect("Invalid input");
-        gloo::console::log!(choice_value);
-        set_funding_needed(Some(choice_value));
+        let choice_value = value
+            .parse::<u128>()
+            .map_err(|e| AppError::Validation(format!("Invalid funding needed: {e}")));
+        gloo::console::log!(format!("{:?}", choice_value));
+        set_funding_needed(choice_value);
     };
 
     let cid_value = move || {
@@ -171,6 +177,7 @@ pub fn CreateProject() -> impl IntoView {
                         </button>
 
                     </form>
+                    <AppErrorBoundary>{move || funding_needed().map(|_| ())}</AppErrorBoundary>
                     <p>{move || pending().then(|| "Loading...")}</p>
                     <p>{move || cid_value()}</p>
                 </div>
@@ -183,7 +190,7 @@ pub fn CreateProject() -> impl IntoView {
                     post_cid=post_cid()
                     department_id=department_id
                     tip_name=tip_name()
-                    funding_needed=funding_needed().unwrap()
+                    funding_needed=funding_needed().unwrap_or_default()
                 />
             </div>
         }.into_any(),
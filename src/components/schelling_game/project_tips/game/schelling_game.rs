@@ -1,11 +1,17 @@
+use crate::components::common::error_view::AppErrorBoundary;
 use crate::components::navigation::nav::Nav;
-use crate::components::schelling_game::project_tips::apply_jurors::ApplyJurors;
-
-use crate::components::schelling_game::project_tips::commit_vote::CommitVote;
-use crate::components::schelling_game::project_tips::draw_jurors::DrawJurors;
-use crate::components::schelling_game::project_tips::reveal_vote::RevealVote;
-use crate::components::schelling_game::project_tips::storage::get_period_fn::get_period_fn;
+use crate::components::schelling_game::common::apply_jurors::ApplyJurors;
+use crate::components::schelling_game::common::commit_vote::CommitVote;
+use crate::components::schelling_game::common::draw_jurors::DrawJurors;
+use crate::components::schelling_game::common::games::ProjectTipsGame;
+use crate::components::schelling_game::common::get_incentives::GetIncentives;
+use crate::components::schelling_game::common::game_timeline::GameTimeline;
+use crate::components::schelling_game::common::get_period_fn::get_period_fn;
+use crate::components::schelling_game::common::reveal_vote::RevealVote;
+use crate::components::schelling_game::common::unstaking::Unstaking;
+use crate::components::schelling_game::project_tips::get_project_funding::GetProjectFunding;
 use crate::services::common_services::polkadot::runtime_types::pallet_schelling_game_shared::types::Period;
+use crate::services::error::AppError;
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
 
@@ -33,37 +39,38 @@ pub fn SchellingGame() -> impl IntoView {
 
 #[component]
 pub fn SchellingGameComponent(project_id: u64) -> impl IntoView {
-    let project_id = move || project_id.clone();
-
-    let period = get_period_fn(project_id());
+    let period = get_period_fn::<ProjectTipsGame>(project_id);
 
-    let myview = move || {
+    let myview = move || -> Result<AnyView, AppError> {
         {
             {
-                // let period_read_signal = period();
-                if let Some(period) = period() {
+                let Some(result) = period.get() else {
+                    return Ok(view! { <div></div> }.into_any());
+                };
+                let period = result?;
+                if let Some(period) = period {
                     let view = match period {
                         Period::Evidence => view! { <div></div> }.into_any(),
                         Period::Staking => {
                             view! {
                                 <div>
-                                    <ApplyJurors project_id=project_id() />
+                                    <ApplyJurors<ProjectTipsGame> id=project_id />
                                 </div>
                             }.into_any()
                         }
                         Period::Drawing => view! {
                             <div>
-                                <DrawJurors project_id=project_id() />
+                                <DrawJurors<ProjectTipsGame> id=project_id />
                             </div>
                         }.into_any(),
                         Period::Commit => view! {
                             <div>
-                                <CommitVote project_id=project_id() />
+                                <CommitVote<ProjectTipsGame> id=project_id />
                             </div>
                         }.into_any(),
                         Period::Vote => view! {
                             <div>
-                                <RevealVote project_id=project_id() />
+                                <RevealVote<ProjectTipsGame> id=project_id />
                             </div>
                         }.into_any(),
                         Period::Appeal => view! { <div></div> }.into_any(),
@@ -73,20 +80,32 @@ pub fn SchellingGameComponent(project_id: u64) -> impl IntoView {
                                     <div class="bg-green-100 border border-green-400 text-green-700 px-4 py-3 rounded border text-[10px] sm:text-base">
                                         You are in Execution phase. Get your incentives
                                     </div>
+                                    <br />
+                                    <GetProjectFunding project_id=project_id />
+                                    <br />
+                                    <GetIncentives<ProjectTipsGame> id=project_id />
+                                    <br />
+                                    <Unstaking<ProjectTipsGame> id=project_id />
 
                                 </div>
                             }
                         }.into_any()
                     };
-                    view
+                    Ok(view)
                 } else {
-                    view! {
+                    Ok(view! {
                         <div class="container mx-auto">
-                            <p class="text-gray-900 dark:text-white">{format!("{:?}", period())}</p>
+                            <p class="text-gray-900 dark:text-white">{format!("{:?}", period)}</p>
                             <p class="text-gray-900 dark:text-white">{"No period"}</p>
+                            <a
+                                href=format!("/project-tips/apply-staking-period/{}", project_id)
+                                class="text-blue-600 hover:underline dark:text-blue-400"
+                            >
+                                "Start the staking period"
+                            </a>
                         </div>
                     }
-                    .into_any()
+                    .into_any())
                 }
             }
         }
@@ -95,9 +114,10 @@ pub fn SchellingGameComponent(project_id: u64) -> impl IntoView {
     view! {
         <div>
             <Nav />
-            // {move || account()}
-            // {move || format!("{:?}", period())}
-            {move || myview()}
+            <GameTimeline<ProjectTipsGame> id=project_id />
+            <AppErrorBoundary on_retry=move |_: ()| period.refetch()>
+                {move || myview()}
+            </AppErrorBoundary>
         </div>
     }
 }
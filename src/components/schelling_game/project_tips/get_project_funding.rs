@@ -1,12 +1,11 @@
-use crate::components::schelling_game::project_tips::get_project_funding_sign_in::SignTransaction;
+use crate::components::schelling_game::common::game_transaction::GameTransaction;
+use crate::components::schelling_game::common::games::{dynamic_call, ProjectTipsGame};
 use crate::services::common_imp::View;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
-use leptos_router::*;
 
 #[component]
 pub fn GetProjectFunding(project_id: u64) -> impl IntoView {
-    // gloo::console::log!(project_id());
     let (current_view, set_current_view) = signal(View::Form);
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
@@ -34,7 +33,7 @@ pub fn GetProjectFunding(project_id: u64) -> impl IntoView {
         View::Success => {
             view! {
                 <div>
-                    <SignTransaction project_id=project_id.clone() />
+                    <GameTransaction tx=dynamic_call::<ProjectTipsGame>("release_tip", &project_id, vec![]) />
 
                 </div>
             }.into_any()
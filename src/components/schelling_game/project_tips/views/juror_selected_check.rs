@@ -1,7 +1,10 @@
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
 use crate::components::navigation::nav::Nav;
-use crate::components::schelling_game::project_tips::rpc::juror_selected::JurorSelected;
+use crate::components::schelling_game::common::games::ProjectTipsGame;
+use crate::components::schelling_game::common::juror_selected::JurorSelected;
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
+use reactive_stores::Store;
 
 #[component]
 pub fn JurorSelectedCheck() -> impl IntoView {
@@ -16,7 +19,8 @@ pub fn JurorSelectedCheck() -> impl IntoView {
         })
     };
 
-    let (check_account, set_check_account) = signal(String::from(""));
+    let state = expect_context::<Store<GlobalState>>();
+    let (check_account, set_check_account) = signal(state.account_address().get_untracked());
 
     let account = untrack(move || project_id());
 
@@ -36,11 +40,12 @@ pub fn JurorSelectedCheck() -> impl IntoView {
                     placeholder="Enter account address here"
                     id="juror-address-checking"
                     class="w-full max-w-xs px-4 py-2 text-sm font-normal text-gray-700 placeholder-gray-400 bg-white border border-gray-300 rounded-md shadow-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-800 dark:text-gray-200 dark:placeholder-gray-500 dark:border-gray-700"
+                    prop:value=check_account
                     on:input=on_account
                 />
                 <br />
                 <br />
-                <JurorSelected project_id=account check_account=check_account />
+                <JurorSelected<ProjectTipsGame> id=account check_account=check_account />
             </div>
         </div>
     }
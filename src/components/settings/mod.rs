@@ -0,0 +1,2 @@
+pub mod node_endpoints;
+pub mod session_settings;
@@ -0,0 +1,221 @@
+use crate::components::navigation::nav::Nav;
+use crate::services::chain_client::ChainClient;
+use crate::services::endpoints::{check_endpoint, Endpoint, EndpointHealth, EndpointStore};
+use codee::string::JsonSerdeCodec;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::storage::use_local_storage;
+use std::collections::HashMap;
+
+#[component]
+pub fn NodeEndpoints() -> impl IntoView {
+    let (endpoint_store, set_endpoint_store, _reset_endpoint_store) =
+        use_local_storage::<EndpointStore, JsonSerdeCodec>("endpoint-store-state");
+    let chain_client = expect_context::<ChainClient>();
+    let connected_url = chain_client.connected_url();
+
+    let (health, set_health) =
+        signal(HashMap::<String, Option<Result<EndpointHealth, String>>>::new());
+    let (name, set_name) = signal(String::new());
+    let (url, set_url) = signal(String::new());
+    let (error_message, set_error_message) = signal(String::new());
+
+    let check = move |url: String| {
+        set_health.update(|h| {
+            h.insert(url.clone(), None);
+        });
+        spawn_local(async move {
+            let result = check_endpoint(&url).await.map_err(|e| e.to_string());
+            set_health.update(move |h| {
+                h.insert(url, Some(result));
+            });
+        });
+    };
+
+    let check_all = move || {
+        for endpoint in endpoint_store.get_untracked().endpoints {
+            check(endpoint.url);
+        }
+    };
+
+    Effect::new(move |_| check_all());
+
+    let add_endpoint = move |e: SubmitEvent| {
+        e.prevent_default();
+        let new_url = url().trim().to_string();
+        if !new_url.starts_with("ws://") && !new_url.starts_with("wss://") {
+            set_error_message("Endpoint must start with ws:// or wss://".to_string());
+            return;
+        }
+        if endpoint_store.with(|store| store.endpoints.iter().any(|e| e.url == new_url)) {
+            set_error_message("This endpoint already exists.".to_string());
+            return;
+        }
+        let endpoint = Endpoint {
+            name: name().trim().to_string(),
+            url: new_url.clone(),
+        };
+        set_endpoint_store.update(move |store| store.endpoints.push(endpoint));
+        set_name(String::new());
+        set_url(String::new());
+        check(new_url);
+    };
+
+    let use_endpoint = move |url: String| {
+        set_endpoint_store.update(move |store| store.active_url = url);
+    };
+
+    let remove_endpoint = move |url: String| {
+        set_endpoint_store.update(move |store| {
+            if store.endpoints.len() > 1 {
+                store.endpoints.retain(|e| e.url != url);
+                if store.active_url == url {
+                    store.active_url = store.endpoints[0].url.clone();
+                }
+            }
+        });
+    };
+
+    view! {
+        <>
+            <Nav />
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <h1 class="text-2xl font-bold mb-4 text-center">Node Endpoints</h1>
+
+                <div class="space-y-2">
+                    {move || {
+                        let store = endpoint_store.get();
+                        store
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                let is_active = endpoint.url == store.active_url;
+                                let is_connected = connected_url.get().as_deref()
+                                    == Some(endpoint.url.as_str());
+                                let health_url = endpoint.url.clone();
+                                let check_url = endpoint.url.clone();
+                                let use_url = endpoint.url.clone();
+                                let remove_url = endpoint.url.clone();
+                                view! {
+                                    <div class="flex flex-col sm:flex-row sm:justify-between sm:items-center gap-2 p-3 border rounded-lg border-gray-300 dark:border-gray-600">
+                                        <div>
+                                            <p class="font-medium">
+                                                {endpoint.name.clone()}
+                                                {if is_active { " (active)" } else { "" }}
+                                                {if is_connected { " - connected" } else { "" }}
+                                            </p>
+                                            <p class="text-sm text-gray-500 dark:text-gray-400 break-all">
+                                                {endpoint.url.clone()}
+                                            </p>
+                                            <p class="text-sm break-all">
+                                                {move || match health.get().get(&health_url).cloned() {
+                                                    Some(Some(Ok(h))) => {
+                                                        format!(
+                                                            "Latency: {:.0} ms | Best block: #{} | Genesis: {}",
+                                                            h.latency_ms,
+                                                            h.best_block,
+                                                            h.genesis_hash,
+                                                        )
+                                                    }
+                                                    Some(Some(Err(e))) => format!("Unreachable: {}", e),
+                                                    Some(None) => "Checking...".to_string(),
+                                                    None => String::new(),
+                                                }}
+                                            </p>
+                                        </div>
+                                        <div class="flex gap-2">
+                                            <button
+                                                type="button"
+                                                class="px-3 py-1 bg-gray-500 text-white text-sm rounded hover:bg-gray-600"
+                                                on:click=move |_| check(check_url.clone())
+                                            >
+                                                Check
+                                            </button>
+                                            <button
+                                                type="button"
+                                                class="px-3 py-1 bg-blue-600 text-white text-sm rounded hover:bg-blue-700 disabled:opacity-50"
+                                                disabled=is_active
+                                                on:click=move |_| use_endpoint(use_url.clone())
+                                            >
+                                                Use
+                                            </button>
+                                            <button
+                                                type="button"
+                                                class="px-3 py-1 bg-red-600 text-white text-sm rounded hover:bg-red-700"
+                                                on:click=move |_| remove_endpoint(remove_url.clone())
+                                            >
+                                                Remove
+                                            </button>
+                                        </div>
+                                    </div>
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                    }}
+                </div>
+
+                <form class="max-w-sm mx-auto mt-6" on:submit=add_endpoint>
+                    <div class="mb-5">
+                        <label
+                            for="endpoint-name"
+                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                        >
+                            Name
+                        </label>
+                        <input
+                            type="text"
+                            id="endpoint-name"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            placeholder="Staging"
+                            required
+                            prop:value=move || name()
+                            on:input=move |e| set_name(event_target_value(&e))
+                        />
+                    </div>
+                    <div class="mb-5">
+                        <label
+                            for="endpoint-url"
+                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                        >
+                            Websocket URL
+                        </label>
+                        <input
+                            type="text"
+                            id="endpoint-url"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            placeholder="wss://example.org:443"
+                            required
+                            prop:value=move || url()
+                            on:input=move |e| {
+                                set_url(event_target_value(&e));
+                                set_error_message(String::new());
+                            }
+                        />
+                    </div>
+                    {move || {
+                        if !error_message().is_empty() {
+                            view! {
+                                <div
+                                    role="alert"
+                                    class="flex p-4 items-center gap-3 mb-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
+                                >
+                                    {error_message()}
+                                </div>
+                            }
+                                .into_any()
+                        } else {
+                            ().into_any()
+                        }
+                    }}
+                    <button
+                        type="submit"
+                        class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                    >
+                        Add Endpoint
+                    </button>
+                </form>
+            </main>
+        </>
+    }
+}
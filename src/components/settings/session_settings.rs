@@ -0,0 +1,73 @@
+use crate::components::navigation::nav::Nav;
+use crate::services::session::SessionSettings;
+use codee::string::JsonSerdeCodec;
+use leptos::prelude::*;
+use leptos_use::storage::use_local_storage;
+
+#[component]
+pub fn SessionSettingsForm() -> impl IntoView {
+    let (settings, set_settings, _reset_settings) =
+        use_local_storage::<SessionSettings, JsonSerdeCodec>("session-settings");
+
+    view! {
+        <>
+            <Nav />
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <h1 class="text-2xl font-bold mb-4 text-center">Session</h1>
+
+                <div class="max-w-sm mx-auto">
+                    <div class="mb-5">
+                        <label
+                            for="idle-timeout"
+                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                        >
+                            Lock after minutes of inactivity (0 to never lock)
+                        </label>
+                        <input
+                            type="number"
+                            id="idle-timeout"
+                            min="0"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            prop:value=move || settings.with(|s| s.idle_timeout_minutes.to_string())
+                            on:input=move |e| {
+                                if let Ok(minutes) = event_target_value(&e).parse::<u32>() {
+                                    set_settings.update(|s| s.idle_timeout_minutes = minutes);
+                                }
+                            }
+                        />
+                    </div>
+                    <div class="flex items-center mb-4">
+                        <input
+                            type="checkbox"
+                            id="lock-on-hidden"
+                            class="w-4 h-4 text-blue-600 bg-gray-100 border-gray-300 rounded focus:ring-blue-500 dark:bg-gray-700 dark:border-gray-600"
+                            prop:checked=move || settings.with(|s| s.lock_on_hidden)
+                            on:change=move |e| {
+                                let checked = event_target_checked(&e);
+                                set_settings.update(|s| s.lock_on_hidden = checked);
+                            }
+                        />
+                        <label for="lock-on-hidden" class="ms-2 text-sm font-medium">
+                            Lock when the tab is hidden
+                        </label>
+                    </div>
+                    <div class="flex items-center mb-4">
+                        <input
+                            type="checkbox"
+                            id="lock-on-blur"
+                            class="w-4 h-4 text-blue-600 bg-gray-100 border-gray-300 rounded focus:ring-blue-500 dark:bg-gray-700 dark:border-gray-600"
+                            prop:checked=move || settings.with(|s| s.lock_on_blur)
+                            on:change=move |e| {
+                                let checked = event_target_checked(&e);
+                                set_settings.update(|s| s.lock_on_blur = checked);
+                            }
+                        />
+                        <label for="lock-on-blur" class="ms-2 text-sm font-medium">
+                            Lock when the window loses focus
+                        </label>
+                    </div>
+                </div>
+            </main>
+        </>
+    }
+}
@@ -1,10 +1,68 @@
+use crate::services::account_secret::CryptoType;
+use crate::services::keystore::{EncryptedSeed, KeystoreError};
+use crate::services::signer::SignerKind;
+use magic_crypt::{new_magic_crypt, MagicCryptTrait};
 use serde::{Deserialize, Serialize};
+use subxt_signer::bip39::Mnemonic;
+use zeroize::Zeroizing;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Account {
+    /// Seed encrypted with magic-crypt by older versions. Emptied once the
+    /// account is migrated to `encrypted_seed`.
+    #[serde(default)]
     pub hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted_seed: Option<EncryptedSeed>,
     pub account_address: String,
     pub name: String,
+    /// Accounts saved before signer selection existed use the local keystore.
+    #[serde(default)]
+    pub signer: SignerKind,
+    #[serde(default)]
+    pub crypto: CryptoType,
+}
+
+/// Seed phrase of an unlocked account, wiped when dropped.
+pub struct UnlockedSeed {
+    pub seed: Zeroizing<String>,
+    /// Set when the account was still in the legacy format and should be
+    /// saved back with this entry.
+    pub migrated: Option<EncryptedSeed>,
+}
+
+impl Account {
+    /// Decrypts the seed phrase, migrating a legacy magic-crypt entry to
+    /// [`EncryptedSeed`].
+    pub fn unlock(&self, password: &str) -> Result<UnlockedSeed, KeystoreError> {
+        if let Some(encrypted_seed) = &self.encrypted_seed {
+            return Ok(UnlockedSeed {
+                seed: encrypted_seed.decrypt(password)?,
+                migrated: None,
+            });
+        }
+        if self.hash.is_empty() {
+            return Err(KeystoreError::Corrupted(
+                "Account has no stored seed".to_string(),
+            ));
+        }
+
+        // The legacy format has no authentication tag, so a wrong password
+        // shows up as a decryption failure or as text that is not a phrase.
+        let mc = new_magic_crypt!(password, 256);
+        let seed = Zeroizing::new(
+            mc.decrypt_base64_to_string(&self.hash)
+                .map_err(|_| KeystoreError::WrongPassword)?,
+        );
+        if Mnemonic::parse(seed.as_str()).is_err() {
+            return Err(KeystoreError::WrongPassword);
+        }
+        let migrated = EncryptedSeed::encrypt(&seed, password)?;
+        Ok(UnlockedSeed {
+            seed,
+            migrated: Some(migrated),
+        })
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -19,3 +77,73 @@ impl Default for AccountStore {
         }
     }
 }
+
+impl AccountStore {
+    pub fn contains(&self, account: &Account) -> bool {
+        self.accounts
+            .iter()
+            .any(|a| a.account_address == account.account_address && a.signer == account.signer)
+    }
+
+    pub fn rename(&mut self, account_address: &str, signer: &SignerKind, name: String) {
+        if let Some(account) = self
+            .accounts
+            .iter_mut()
+            .find(|a| a.account_address == account_address && &a.signer == signer)
+        {
+            account.name = name;
+        }
+    }
+
+    /// Copy of the store with every local keystore entry encrypted under
+    /// `new_password`. Fails without touching anything if one entry does not
+    /// unlock with `current_password`.
+    pub fn with_new_password(
+        &self,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<Self, KeystoreError> {
+        let mut store = self.clone();
+        for account in store
+            .accounts
+            .iter_mut()
+            .filter(|a| a.signer == SignerKind::LocalKeystore)
+        {
+            let seed = match account.unlock(current_password) {
+                Ok(UnlockedSeed { seed, .. }) => seed,
+                Err(KeystoreError::WrongPassword) => {
+                    return Err(KeystoreError::WrongPasswordFor(account.name.clone()))
+                }
+                Err(e) => return Err(e),
+            };
+            account.hash = String::new();
+            account.encrypted_seed = Some(EncryptedSeed::encrypt(&seed, new_password)?);
+        }
+        Ok(store)
+    }
+
+    /// Appends the accounts of `backup` that are not in the store yet and
+    /// returns how many were added.
+    pub fn merge(&mut self, backup: AccountStore) -> usize {
+        let mut added = 0;
+        for account in backup.accounts {
+            if !self.contains(&account) {
+                self.accounts.push(account);
+                added += 1;
+            }
+        }
+        added
+    }
+
+    /// Replaces the legacy seed of `account_address` with `encrypted_seed`.
+    pub fn save_migrated(&mut self, account_address: &str, encrypted_seed: EncryptedSeed) {
+        if let Some(account) = self
+            .accounts
+            .iter_mut()
+            .find(|a| a.account_address == account_address && a.signer == SignerKind::LocalKeystore)
+        {
+            account.hash = String::new();
+            account.encrypted_seed = Some(encrypted_seed);
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use crate::components::navigation::nav::Nav;
 use crate::components::signing::accounts_store::{Account, AccountStore};
+use crate::services::account_secret::{secret_uri, AccountKeypair, CryptoType};
+use crate::services::keystore::EncryptedSeed;
+use crate::services::signer::SignerKind;
+use crate::services::tauri_keystore;
 use codee::string::JsonSerdeCodec;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 use leptos_use::storage::use_local_storage;
-use magic_crypt::{new_magic_crypt, MagicCryptTrait};
-use subxt_core::utils::AccountId32;
-use subxt_signer::{bip39::Mnemonic, sr25519::Keypair};
 
 #[component]
 pub fn AddAccount() -> impl IntoView {
@@ -15,6 +17,9 @@ pub fn AddAccount() -> impl IntoView {
     let (seed, set_seed) = signal("".to_string());
     let (password, set_password) = signal("".to_string());
     let (name, set_name) = signal("".to_string());
+    let (derivation_path, set_derivation_path) = signal(String::new());
+    let (bip39_password, set_bip39_password) = signal(String::new());
+    let (crypto_type, set_crypto_type) = signal(CryptoType::default());
     let (confirm_password, set_confirm_password) = signal(String::from(""));
     let (form_submission, set_form_submission) = signal(true);
     let (error_message, set_error_message) = signal(String::from(""));
@@ -22,32 +27,55 @@ pub fn AddAccount() -> impl IntoView {
 
     let submit_click = move |e: SubmitEvent| {
         e.prevent_default();
-        let mnemonic = Mnemonic::parse(seed()).unwrap();
-        let keypair = Keypair::from_phrase(&mnemonic, None).unwrap();
-        let account_address = keypair.public_key().to_account_id();
-        let account_string = format!("{}", account_address);
-        gloo::console::log!(account_string.clone());
+        // The derivation path and BIP39 password are kept in the stored
+        // secret, so one phrase can back several accounts.
+        let secret = match secret_uri(&seed(), &derivation_path(), &bip39_password()) {
+            Ok(secret) => secret,
+            Err(e) => {
+                set_error_message(e.to_string());
+                return;
+            }
+        };
+        let account_string = match AccountKeypair::from_secret(crypto_type(), &secret) {
+            Ok(keypair) => keypair.account_id().to_string(),
+            Err(e) => {
+                set_error_message(e.to_string());
+                return;
+            }
+        };
 
-        let mc = new_magic_crypt!(password(), 256);
-        let base64 = mc.encrypt_str_to_base64(seed());
         if !is_valid() {
             set_error_message("Passwords do not match.".to_string());
-        } else {
-            let account_exists = account_store.with(|store| {
-                store
-                    .accounts
-                    .iter()
-                    .any(|a| a.account_address == account_string.clone())
+        } else if tauri_keystore::is_available() {
+            // The desktop backend encrypts and keeps the seed itself.
+            spawn_local(async move {
+                match tauri_keystore::import_account(&name(), crypto_type(), &secret, &password())
+                    .await
+                {
+                    Ok(_) => set_form_submission(false),
+                    Err(e) => set_error_message(e.to_string()),
+                }
             });
+        } else {
+            let mut new_account = Account {
+                hash: String::new(),
+                encrypted_seed: None,
+                account_address: account_string,
+                name: name(),
+                signer: SignerKind::LocalKeystore,
+                crypto: crypto_type(),
+            };
 
-            if account_exists {
+            if account_store.with(|store| store.contains(&new_account)) {
                 set_error_message("This account already exists.".to_string());
             } else {
-                let new_account = Account {
-                    hash: base64,
-                    account_address: account_string.clone(),
-                    name: name().clone(),
-                };
+                match EncryptedSeed::encrypt(&secret, &password()) {
+                    Ok(encrypted_seed) => new_account.encrypted_seed = Some(encrypted_seed),
+                    Err(e) => {
+                        set_error_message(e.to_string());
+                        return;
+                    }
+                }
 
                 set_account_store.update(move |store| {
                     store.accounts.push(new_account);
@@ -91,6 +119,77 @@ pub fn AddAccount() -> impl IntoView {
                                         />
                                     </div>
 
+                                    <div class="mb-5">
+                                        <label
+                                            for="crypto-type"
+                                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                        >
+                                            Key type
+                                        </label>
+                                        <select
+                                            id="crypto-type"
+                                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:text-white"
+                                            on:change=move |e| {
+                                                if let Some(crypto) = CryptoType::from_label(
+                                                    &event_target_value(&e),
+                                                ) {
+                                                    set_crypto_type(crypto);
+                                                }
+                                                set_error_message(String::new());
+                                            }
+                                        >
+                                            {CryptoType::ALL
+                                                .into_iter()
+                                                .map(|crypto| {
+                                                    view! {
+                                                        <option
+                                                            value=crypto.label()
+                                                            selected=move || crypto_type() == crypto
+                                                        >
+                                                            {crypto.label()}
+                                                        </option>
+                                                    }
+                                                })
+                                                .collect::<Vec<_>>()}
+                                        </select>
+                                    </div>
+
+                                    <div class="mb-5">
+                                        <label
+                                            for="derivation-path"
+                                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                        >
+                                            Derivation path (optional)
+                                        </label>
+                                        <input
+                                            type="text"
+                                            id="derivation-path"
+                                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                            placeholder="//juror"
+                                            prop:value=move || derivation_path()
+                                            on:input=move |e| {
+                                                set_derivation_path(event_target_value(&e));
+                                                set_error_message(String::new());
+                                            }
+                                        />
+                                    </div>
+
+                                    <div class="mb-5">
+                                        <label
+                                            for="bip39-password"
+                                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                        >
+                                            BIP39 password (optional)
+                                        </label>
+                                        <input
+                                            type="password"
+                                            id="bip39-password"
+                                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                            prop:value=move || bip39_password()
+                                            on:input=move |e| set_bip39_password(event_target_value(&e))
+                                        />
+                                    </div>
+
                                     <div class="mb-5">
                                         <label
                                             for="name"
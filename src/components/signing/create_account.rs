@@ -1,41 +1,68 @@
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
 use crate::components::navigation::nav::Nav;
+use crate::components::signing::accounts_store::{Account, AccountStore};
+use crate::services::account_secret::{
+    generate_phrase, secret_uri, AccountKeypair, CryptoType, WORD_COUNTS,
+};
+use crate::services::keystore::EncryptedSeed;
+use crate::services::signer::SignerKind;
+use crate::services::tauri_keystore;
+use codee::string::JsonSerdeCodec;
+use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use leptos_icons::*;
+use leptos_use::storage::use_local_storage;
 use leptos_use::{use_clipboard_with_options, UseClipboardOptions, UseClipboardReturn};
+use reactive_stores::Store;
+use zeroize::Zeroizing;
 
-use wasm_bindgen::prelude::*;
-
-mod commands {
-    use super::*;
-
-    #[wasm_bindgen]
-    extern "C" {
-        #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke)]
-        async fn invoke_without_args(cmd: &str) -> JsValue;
-    }
-
-    pub async fn create_seed() -> String {
-        invoke_without_args("create_seed")
-            .await
-            .as_string()
-            .unwrap()
-    }
-}
+/// Number of words the user types back to show the seed was written down.
+const CONFIRM_WORDS: usize = 4;
 
 #[derive(Clone, Copy, PartialEq)]
 enum AppState {
     Idle,
     ShowingSeed,
     ConfirmingSeed,
+    SettingPassword,
+    Created,
+}
+
+/// Positions of the words asked back, in ascending order.
+fn random_word_indices(words: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..words).collect();
+    for i in (1..indices.len()).rev() {
+        let j = (js_sys::Math::random() * (i + 1) as f64) as usize;
+        indices.swap(i, j);
+    }
+    indices.truncate(CONFIRM_WORDS);
+    indices.sort_unstable();
+    indices
 }
 
 #[component]
 pub fn CreateAccount() -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+    let (account_store, set_account_store, _reset_account_store) =
+        use_local_storage::<AccountStore, JsonSerdeCodec>("account-store-state");
     let (app_state, set_app_state) = signal(AppState::Idle);
-    let (seed_phrase, set_seed_phrase) = signal(None::<String>);
-    let (word_inputs, set_word_inputs) = signal(vec![String::new(); 12]);
+    let (word_count, set_word_count) = signal(12usize);
+    let (crypto_type, set_crypto_type) = signal(CryptoType::default());
+    let (seed_phrase, set_seed_phrase) = signal(None::<Zeroizing<String>>);
+    // Once the backup is confirmed the phrase is no longer shown, so it
+    // leaves the reactive graph until the account is saved.
+    let confirmed_phrase = StoredValue::new(None::<Zeroizing<String>>);
+    let (confirm_indices, set_confirm_indices) = signal(Vec::<usize>::new());
+    let (word_inputs, set_word_inputs) = signal(vec![String::new(); CONFIRM_WORDS]);
     let (is_match, set_is_match) = signal(None::<bool>);
+    let (name, set_name) = signal(String::new());
+    let (derivation_path, set_derivation_path) = signal(String::new());
+    let (bip39_password, set_bip39_password) = signal(String::new());
+    let (password, set_password) = signal(String::new());
+    let (confirm_password, set_confirm_password) = signal(String::new());
+    let (error_message, set_error_message) = signal(String::new());
+    let (created_address, set_created_address) = signal(String::new());
 
     let UseClipboardReturn {
         is_supported,
@@ -46,25 +73,130 @@ pub fn CreateAccount() -> impl IntoView {
 
     let generate_seed = move |_| {
         spawn_local(async move {
-            let s = commands::create_seed().await;
-            set_seed_phrase.set(Some(s));
+            let words = word_count.get_untracked();
+            // The browser build has no backend, so the phrase is made here.
+            let phrase = if tauri_keystore::is_available() {
+                tauri_keystore::create_seed(words)
+                    .await
+                    .map_err(|e| e.to_string())
+            } else {
+                generate_phrase(words)
+                    .map(Zeroizing::new)
+                    .map_err(|e| e.to_string())
+            };
+            match phrase {
+                Ok(phrase) => set_seed_phrase.set(Some(phrase)),
+                Err(e) => {
+                    set_error_message.set(e);
+                    return;
+                }
+            }
             set_app_state.set(AppState::ShowingSeed);
         });
     };
 
     let confirm_seed = move |_| {
         if let Some(ref phrase) = seed_phrase.get() {
-            let original_words: Vec<String> = phrase.split_whitespace().map(String::from).collect();
-            let entered_words: Vec<String> = word_inputs.get().into_iter().collect();
+            let original_words: Vec<&str> = phrase.split_whitespace().collect();
+            let matches = confirm_indices
+                .get()
+                .into_iter()
+                .zip(word_inputs.get())
+                .all(|(index, entered)| original_words.get(index) == Some(&entered.trim()));
 
-            set_is_match.set(Some(original_words == entered_words));
+            set_is_match.set(Some(matches));
+            if matches {
+                confirmed_phrase.set_value(Some(phrase.clone()));
+                set_seed_phrase.set(None);
+                set_app_state.set(AppState::SettingPassword);
+            }
         }
     };
 
     let confirm_seed_button = move |_| {
+        set_confirm_indices.set(random_word_indices(word_count.get_untracked()));
+        set_word_inputs.set(vec![String::new(); CONFIRM_WORDS]);
+        set_is_match.set(None);
         set_app_state.set(AppState::ConfirmingSeed);
     };
 
+    let signed_in = move |address: String, secret: Option<String>, signer: SignerKind| {
+        *state.account_address().write() = address.clone();
+        *state.mnemonic_phrase().write() = secret.map(Zeroizing::new);
+        *state.phase_exists_in_state().write() = true;
+        *state.signer_kind().write() = signer;
+        *state.crypto_type().write() = crypto_type.get_untracked();
+        set_seed_phrase.set(None);
+        confirmed_phrase.set_value(None);
+        set_bip39_password.set(String::new());
+        set_password.set(String::new());
+        set_confirm_password.set(String::new());
+        set_created_address.set(address);
+        set_app_state.set(AppState::Created);
+    };
+
+    let save_account = move |e: SubmitEvent| {
+        e.prevent_default();
+        let Some(phrase) = confirmed_phrase.get_value() else {
+            return;
+        };
+        if password() != confirm_password() {
+            set_error_message.set("Passwords do not match.".to_string());
+            return;
+        }
+        let name = name().trim().to_string();
+        // Like an added account, the stored secret keeps the derivation path
+        // and BIP39 password.
+        let secret = match secret_uri(&phrase, &derivation_path(), &bip39_password()) {
+            Ok(secret) => secret,
+            Err(e) => {
+                set_error_message.set(e.to_string());
+                return;
+            }
+        };
+
+        if tauri_keystore::is_available() {
+            // The backend keeps the new account unlocked.
+            spawn_local(async move {
+                match tauri_keystore::import_account(
+                    &name,
+                    crypto_type.get_untracked(),
+                    &secret,
+                    &password.get_untracked(),
+                )
+                .await
+                {
+                    Ok(account) => signed_in(account.address, None, SignerKind::TauriKeystore),
+                    Err(e) => set_error_message.set(e.to_string()),
+                }
+            });
+            return;
+        }
+
+        let crypto = crypto_type.get_untracked();
+        let result = AccountKeypair::from_secret(crypto, &secret).and_then(|keypair| {
+            Ok(Account {
+                hash: String::new(),
+                encrypted_seed: Some(EncryptedSeed::encrypt(&secret, &password())?),
+                account_address: keypair.account_id().to_string(),
+                name,
+                signer: SignerKind::LocalKeystore,
+                crypto,
+            })
+        });
+        match result {
+            Ok(account) if account_store.with_untracked(|store| store.contains(&account)) => {
+                set_error_message.set("This account already exists.".to_string());
+            }
+            Ok(account) => {
+                let address = account.account_address.clone();
+                set_account_store.update(move |store| store.accounts.push(account));
+                signed_in(address, Some(secret), SignerKind::LocalKeystore);
+            }
+            Err(e) => set_error_message.set(e.to_string()),
+        }
+    };
+
     let update_word_input = move |index: usize, value: String| {
         set_word_inputs.update(move |words| {
             words[index] = value;
@@ -81,6 +213,66 @@ pub fn CreateAccount() -> impl IntoView {
                     AppState::Idle => {
                         view! {
                             <>
+                                <label
+                                    for="word-count"
+                                    class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                >
+                                    Number of words
+                                </label>
+                                <select
+                                    id="word-count"
+                                    class="mb-4 bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:text-white"
+                                    on:change=move |e| {
+                                        if let Ok(words) = event_target_value(&e).parse() {
+                                            set_word_count.set(words);
+                                        }
+                                    }
+                                >
+                                    {WORD_COUNTS
+                                        .into_iter()
+                                        .map(|words| {
+                                            view! {
+                                                <option
+                                                    value=words.to_string()
+                                                    selected=move || word_count.get() == words
+                                                >
+                                                    {words.to_string()}
+                                                </option>
+                                            }
+                                        })
+                                        .collect::<Vec<_>>()}
+                                </select>
+                                <label
+                                    for="crypto-type"
+                                    class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                >
+                                    Key type
+                                </label>
+                                <select
+                                    id="crypto-type"
+                                    class="mb-4 bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:text-white"
+                                    on:change=move |e| {
+                                        if let Some(crypto) = CryptoType::from_label(
+                                            &event_target_value(&e),
+                                        ) {
+                                            set_crypto_type.set(crypto);
+                                        }
+                                    }
+                                >
+                                    {CryptoType::ALL
+                                        .into_iter()
+                                        .map(|crypto| {
+                                            view! {
+                                                <option
+                                                    value=crypto.label()
+                                                    selected=move || crypto_type.get() == crypto
+                                                >
+                                                    {crypto.label()}
+                                                </option>
+                                            }
+                                        })
+                                        .collect::<Vec<_>>()}
+                                </select>
                                 <button
                                     on:click=generate_seed
                                     class="w-full bg-blue-500 hover:bg-blue-600 dark:bg-blue-600 dark:hover:bg-blue-700 text-white font-semibold py-2 px-4 rounded shadow transition"
@@ -91,6 +283,16 @@ pub fn CreateAccount() -> impl IntoView {
                                 <p class="mt-4 text-center text-gray-600 dark:text-gray-400">
                                     "Click 'Generate Seed' to begin."
                                 </p>
+                                {move || {
+                                    (!error_message().is_empty())
+                                        .then(|| {
+                                            view! {
+                                                <p class="text-red-600 dark:text-red-400 mt-2 text-center font-medium">
+                                                    {error_message()}
+                                                </p>
+                                            }
+                                        })
+                                }}
                             </>
                         }
                             .into_view()
@@ -108,14 +310,18 @@ pub fn CreateAccount() -> impl IntoView {
                                             </p>
                                             <div class="flex flex-col sm:flex-row sm:items-center sm:justify-between gap-2">
                                                 <pre class="bg-gray-100 dark:bg-gray-800 p-4 rounded-md overflow-x-auto font-mono text-sm border border-gray-300 dark:border-gray-700 flex-1">
-                                                    {phrase.clone()}
+                                                    {phrase.to_string()}
 
                                                 </pre>
 
                                                 <button
                                                     on:click={
                                                         let copy = copy.clone();
-                                                        move |_| copy(&seed_phrase().unwrap())
+                                                        move |_| {
+                                                            if let Some(phrase) = seed_phrase.get_untracked() {
+                                                                copy(&phrase);
+                                                            }
+                                                        }
                                                     }
                                                     class="whitespace-nowrap self-end bg-blue-500 hover:bg-blue-600 dark:bg-blue-600 dark:hover:bg-blue-700 text-white font-semibold py-2 px-4 rounded transition"
                                                 >
@@ -163,13 +369,17 @@ pub fn CreateAccount() -> impl IntoView {
                             <div class="mt-6 space-y-6 animate-fade-in-down">
                                 <div class="space-y-4">
                                     <h2 class="text-xl font-semibold text-center">Confirm Seed</h2>
+                                    <p class="text-center text-gray-600 dark:text-gray-400">
+                                        "Enter the requested words of your seed."
+                                    </p>
                                     <div class="grid grid-cols-1 sm:grid-cols-2 gap-3">
                                         {move || {
                                             word_inputs
                                                 .get()
                                                 .into_iter()
+                                                .zip(confirm_indices.get())
                                                 .enumerate()
-                                                .map(|(i, val)| {
+                                                .map(|(i, (val, index))| {
                                                     view! {
                                                         <input
                                                             on:input=move |ev| update_word_input(
@@ -177,7 +387,7 @@ pub fn CreateAccount() -> impl IntoView {
                                                                 event_target_value(&ev),
                                                             )
                                                             prop:value=val
-                                                            placeholder=format!("Word {}", i + 1)
+                                                            placeholder=format!("Word {}", index + 1)
                                                             type="text"
                                                             class="p-2 border border-gray-300 dark:border-gray-600 rounded-md bg-white dark:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-blue-500 dark:focus:ring-blue-400 transition"
                                                         />
@@ -197,14 +407,6 @@ pub fn CreateAccount() -> impl IntoView {
                                 </div>
 
                                 {move || match is_match.get() {
-                                    Some(true) => {
-                                        view! {
-                                            <p class="text-green-600 dark:text-green-400 mt-2 text-center font-medium">
-                                                "✅ Match confirmed!"
-                                            </p>
-                                        }
-                                            .into_any()
-                                    }
                                     Some(false) => {
                                         view! {
                                             <p class="text-red-600 dark:text-red-400 mt-2 text-center font-medium">
@@ -213,13 +415,145 @@ pub fn CreateAccount() -> impl IntoView {
                                         }
                                             .into_any()
                                     }
-                                    None => ().into_view().into_any(),
+                                    _ => ().into_view().into_any(),
                                 }}
                             </div>
                         }
                             .into_view()
                             .into_any()
                     }
+                    AppState::SettingPassword => {
+
+                        view! {
+                            <form class="mt-6 max-w-sm mx-auto" on:submit=save_account>
+                                <p class="mb-4 text-green-600 dark:text-green-400 text-center font-medium">
+                                    "✅ Match confirmed!"
+                                </p>
+                                <div class="mb-5">
+                                    <label
+                                        for="name"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        Account Name
+                                    </label>
+                                    <input
+                                        type="text"
+                                        id="name"
+                                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                        required
+                                        prop:value=move || name()
+                                        on:input=move |e| set_name(event_target_value(&e))
+                                    />
+                                </div>
+                                <div class="mb-5">
+                                    <label
+                                        for="derivation-path"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        Derivation path (optional)
+                                    </label>
+                                    <input
+                                        type="text"
+                                        id="derivation-path"
+                                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                        placeholder="//juror"
+                                        prop:value=move || derivation_path()
+                                        on:input=move |e| {
+                                            set_derivation_path(event_target_value(&e));
+                                            set_error_message(String::new());
+                                        }
+                                    />
+                                </div>
+                                <div class="mb-5">
+                                    <label
+                                        for="bip39-password"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        BIP39 password (optional)
+                                    </label>
+                                    <input
+                                        type="password"
+                                        id="bip39-password"
+                                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                        prop:value=move || bip39_password()
+                                        on:input=move |e| set_bip39_password(event_target_value(&e))
+                                    />
+                                </div>
+                                <div class="mb-5">
+                                    <label
+                                        for="password"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        Your password
+                                    </label>
+                                    <input
+                                        type="password"
+                                        id="password"
+                                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                        required
+                                        prop:value=move || password()
+                                        on:input=move |e| {
+                                            set_password(event_target_value(&e));
+                                            set_error_message(String::new());
+                                        }
+                                    />
+                                </div>
+                                <div class="mb-5">
+                                    <label
+                                        for="confirm-password"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        Confirm password
+                                    </label>
+                                    <input
+                                        type="password"
+                                        id="confirm-password"
+                                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                        required
+                                        prop:value=move || confirm_password()
+                                        on:input=move |e| {
+                                            set_confirm_password(event_target_value(&e));
+                                            set_error_message(String::new());
+                                        }
+                                    />
+                                </div>
+                                {move || {
+                                    (!error_message().is_empty())
+                                        .then(|| {
+                                            view! {
+                                                <div
+                                                    role="alert"
+                                                    class="flex p-4 items-center gap-3 mb-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
+                                                >
+                                                    {error_message()}
+                                                </div>
+                                            }
+                                        })
+                                }}
+                                <button
+                                    type="submit"
+                                    class="w-full bg-blue-500 hover:bg-blue-600 dark:bg-blue-600 dark:hover:bg-blue-700 text-white font-semibold py-2 px-4 rounded shadow transition"
+                                >
+                                    Create Account
+                                </button>
+                            </form>
+                        }
+                            .into_view()
+                            .into_any()
+                    }
+                    AppState::Created => {
+
+                        view! {
+                            <div
+                                role="alert"
+                                class="mt-6 max-w-sm mx-auto p-4 border-l-4 border-green-500 bg-green-100 text-green-800 rounded-xl dark:bg-green-900 dark:text-green-200 break-all"
+                            >
+                                {move || format!("Account {} created, you are signed in.", created_address())}
+                            </div>
+                        }
+                            .into_view()
+                            .into_any()
+                    }
                 }}
             </main>
         </>
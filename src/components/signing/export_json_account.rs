@@ -0,0 +1,179 @@
+use crate::components::navigation::nav::Nav;
+use crate::components::signing::accounts_store::{AccountStore, UnlockedSeed};
+use crate::services::account_secret::{keypair_from_secret, CryptoType};
+use crate::services::polkadot_json::PolkadotJsonKeystore;
+use crate::services::signer::SignerKind;
+use codee::string::JsonSerdeCodec;
+use gloo::file::{Blob, ObjectUrl};
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos_use::storage::use_local_storage;
+
+/// Writes a local keystore account to a JSON file that polkadot.js, Talisman
+/// and SubWallet can import. The file uses the account password. Only sr25519
+/// accounts are offered.
+#[component]
+pub fn ExportJsonAccount() -> impl IntoView {
+    let (account_store, set_account_store, _reset_account_store) =
+        use_local_storage::<AccountStore, JsonSerdeCodec>("account-store-state");
+    let (selected, set_selected) = signal(String::new());
+    let (password, set_password) = signal(String::new());
+    let (error_message, set_error_message) = signal(String::new());
+    let (download, set_download) = signal(None::<(String, String)>);
+    // Revokes the previous blob URL when replaced or when the page is left.
+    let object_url = StoredValue::new_local(None::<ObjectUrl>);
+
+    let local_accounts = move || {
+        account_store.with(|store| {
+            store
+                .accounts
+                .iter()
+                .filter(|a| {
+                    a.signer == SignerKind::LocalKeystore && a.crypto == CryptoType::Sr25519
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let export = move |e: SubmitEvent| {
+        e.prevent_default();
+        set_download(None);
+        let Some(account) = account_store.with_untracked(|store| {
+            store
+                .accounts
+                .iter()
+                .find(|a| {
+                    a.account_address == selected.get_untracked()
+                        && a.signer == SignerKind::LocalKeystore
+                })
+                .cloned()
+        }) else {
+            set_error_message("Select an account.".to_string());
+            return;
+        };
+
+        let result = account
+            .unlock(&password())
+            .and_then(|UnlockedSeed { seed, migrated }| {
+                if let Some(encrypted_seed) = migrated {
+                    set_account_store.update(|store| {
+                        store.save_migrated(&account.account_address, encrypted_seed)
+                    });
+                }
+                let keypair = keypair_from_secret(&seed)?;
+                PolkadotJsonKeystore::encrypt(&keypair, &account.name, &password())?.to_json()
+            });
+        match result {
+            Ok(json) => {
+                let url = ObjectUrl::from(Blob::new_with_options(
+                    json.as_str(),
+                    Some("application/json"),
+                ));
+                set_download(Some((
+                    url.to_string(),
+                    format!("{}.json", account.account_address),
+                )));
+                object_url.set_value(Some(url));
+                set_password(String::new());
+            }
+            Err(e) => set_error_message(e.to_string()),
+        }
+    };
+
+    view! {
+        <>
+            <Nav />
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <h1 class="text-2xl font-bold mb-4 text-center">Export JSON Account</h1>
+                <form class="max-w-sm mx-auto" on:submit=export>
+                    <div class="mb-5">
+                        <label
+                            for="account"
+                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                        >
+                            Account
+                        </label>
+                        <select
+                            id="account"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:text-white"
+                            required
+                            on:change=move |e| {
+                                set_selected(event_target_value(&e));
+                                set_download(None);
+                            }
+                        >
+                            <option value="">"Select an account"</option>
+                            {move || {
+                                local_accounts()
+                                    .into_iter()
+                                    .map(|account| {
+                                        view! {
+                                            <option value=account.account_address.clone()>
+                                                {format!("{}: {}", account.name, account.account_address)}
+                                            </option>
+                                        }
+                                    })
+                                    .collect::<Vec<_>>()
+                            }}
+                        </select>
+                    </div>
+                    <div class="mb-5">
+                        <label
+                            for="password"
+                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                        >
+                            Your password
+                        </label>
+                        <input
+                            type="password"
+                            id="password"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            required
+                            prop:value=move || password()
+                            on:input=move |e| {
+                                set_password(event_target_value(&e));
+                                set_error_message(String::new());
+                            }
+                        />
+                    </div>
+                    {move || {
+                        (!error_message().is_empty())
+                            .then(|| {
+                                view! {
+                                    <div
+                                        role="alert"
+                                        class="flex p-4 items-center gap-3 mb-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
+                                    >
+                                        {error_message()}
+                                    </div>
+                                }
+                            })
+                    }}
+                    <button
+                        type="submit"
+                        class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                    >
+                        Export
+                    </button>
+                </form>
+                {move || {
+                    download()
+                        .map(|(href, file_name)| {
+                            view! {
+                                <p class="max-w-sm mx-auto mt-4">
+                                    <a
+                                        href=href
+                                        download=file_name.clone()
+                                        class="text-blue-600 underline dark:text-blue-400"
+                                    >
+                                        {format!("Download {file_name}")}
+                                    </a>
+                                </p>
+                            }
+                        })
+                }}
+            </main>
+        </>
+    }
+}
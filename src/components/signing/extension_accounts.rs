@@ -0,0 +1,131 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
+use crate::components::signing::accounts_store::{Account, AccountStore};
+use crate::services::account_secret::CryptoType;
+use crate::services::common_services::{get_accounts, InjectedAccount};
+use crate::services::error::AppError;
+use crate::services::signer::SignerKind;
+use codee::string::JsonSerdeCodec;
+use leptos::prelude::*;
+use leptos_use::storage::use_local_storage;
+use reactive_stores::Store;
+
+/// Signs in with an account from a browser wallet extension. No password is
+/// needed as the extension keeps the keys and asks for approval itself.
+#[component]
+pub fn ExtensionAccounts() -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+    let account = state.account_address();
+    let mnemonic_phrase = state.mnemonic_phrase();
+    let phase_exists_in_state = state.phase_exists_in_state();
+    let signer_kind = state.signer_kind();
+    let (account_store, set_account_store, _reset_account_store) =
+        use_local_storage::<AccountStore, JsonSerdeCodec>("account-store-state");
+
+    let connect_action: Action<(), Result<Vec<InjectedAccount>, AppError>, LocalStorage> =
+        Action::new_unsync(|_: &()| async move { get_accounts().await });
+    let pending = connect_action.pending();
+    let connected_accounts = connect_action.value();
+
+    let handle_select_account = move |address: String, name: String, source: String| {
+        let kind = SignerKind::InjectedExtension { source };
+        set_account_store.update(|store| {
+            let saved = store
+                .accounts
+                .iter()
+                .any(|a| a.account_address == address && a.signer == kind);
+            if !saved {
+                store.accounts.push(Account {
+                    hash: String::new(),
+                    encrypted_seed: None,
+                    account_address: address.clone(),
+                    name,
+                    signer: kind.clone(),
+                    crypto: CryptoType::default(),
+                });
+            }
+        });
+        *account.write() = address;
+        *mnemonic_phrase.write() = None;
+        *phase_exists_in_state.write() = true;
+        *signer_kind.write() = kind;
+    };
+
+    let account_button = move |address: String, name: String, source: String| {
+        view! {
+            <li>
+                <button
+                    type="button"
+                    class="w-full text-left p-2 rounded-md hover:bg-blue-100 dark:hover:bg-gray-700 text-gray-900 dark:text-white"
+                    id=format!("extension-account-{}", address.clone())
+                    on:click={
+                        let address = address.clone();
+                        let name = name.clone();
+                        let source = source.clone();
+                        move |_| {
+                            handle_select_account(address.clone(), name.clone(), source.clone())
+                        }
+                    }
+                >
+                    {name.clone()}
+                    :
+                    {address.clone()}
+                    <span class="ml-2 text-xs text-gray-500 dark:text-gray-400">{source.clone()}</span>
+                </button>
+            </li>
+        }
+    };
+
+    let saved_accounts = move || {
+        account_store.with(|store| {
+            store
+                .accounts
+                .iter()
+                .filter_map(|a| match &a.signer {
+                    SignerKind::InjectedExtension { source } => Some(account_button(
+                        a.account_address.clone(),
+                        a.name.clone(),
+                        source.clone(),
+                    )),
+                    SignerKind::LocalKeystore
+                    | SignerKind::TauriKeystore
+                    | SignerKind::WatchOnly => None,
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let extension_accounts = move || {
+        connected_accounts().map(|result| {
+            result.map(|accounts| {
+                accounts
+                    .into_iter()
+                    .map(|a| account_button(a.address, a.name.unwrap_or_default(), a.source))
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+
+    view! {
+        <div class="mt-6 max-w-4xl mx-auto">
+            <h3 class="text-lg font-semibold mb-2 text-gray-900 dark:text-white">
+                Wallet Extension
+            </h3>
+            <ul class="space-y-2">{saved_accounts}</ul>
+            <button
+                type="button"
+                id="connect-extension"
+                class="mt-2 text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                on:click=move |_| {
+                    connect_action.dispatch(());
+                }
+            >
+                Connect wallet extension
+            </button>
+            <p>{move || pending().then(|| "Loading...")}</p>
+            <AppErrorBoundary>
+                <ul class="space-y-2">{extension_accounts}</ul>
+            </AppErrorBoundary>
+        </div>
+    }
+}
@@ -0,0 +1,188 @@
+use crate::components::navigation::nav::Nav;
+use crate::components::signing::accounts_store::{Account, AccountStore};
+use crate::services::account_secret::{secret_key_string, CryptoType};
+use crate::services::keystore::{EncryptedSeed, KeystoreError};
+use crate::services::polkadot_json::PolkadotJsonKeystore;
+use crate::services::signer::SignerKind;
+use crate::services::tauri_keystore;
+use codee::string::JsonSerdeCodec;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::storage::use_local_storage;
+use subxt::utils::AccountId32;
+
+/// Adds an account from a polkadot.js, Talisman or SubWallet JSON file. The
+/// key is saved in the local keystore under the password of the file.
+#[component]
+pub fn ImportJsonAccount() -> impl IntoView {
+    let (account_store, set_account_store, _reset_account_store) =
+        use_local_storage::<AccountStore, JsonSerdeCodec>("account-store-state");
+    let (json, set_json) = signal(String::new());
+    let (name, set_name) = signal(String::new());
+    let (password, set_password) = signal(String::new());
+    let (error_message, set_error_message) = signal(String::new());
+    let (imported_address, set_imported_address) = signal(None::<String>);
+
+    let read_file = move |e: leptos::ev::Event| {
+        set_error_message(String::new());
+        let input = event_target::<web_sys::HtmlInputElement>(&e);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            match gloo::file::futures::read_as_text(&gloo::file::File::from(file)).await {
+                Ok(text) => {
+                    if let Ok(keystore) = PolkadotJsonKeystore::parse(&text) {
+                        if let Some(file_name) = keystore.name() {
+                            set_name(file_name.to_string());
+                        }
+                    }
+                    set_json(text);
+                }
+                Err(e) => set_error_message(format!("Cannot read the file: {e}")),
+            }
+        });
+    };
+
+    let import = move |json: &str, password: &str| -> Result<Account, KeystoreError> {
+        let keystore = PolkadotJsonKeystore::parse(json)?;
+        let keypair = keystore.decrypt(password)?;
+        let account_address = AccountId32(keypair.public.to_bytes()).to_string();
+        let mut account = Account {
+            hash: String::new(),
+            encrypted_seed: None,
+            account_address,
+            name: name.get_untracked().trim().to_string(),
+            signer: SignerKind::LocalKeystore,
+            crypto: CryptoType::Sr25519,
+        };
+        if account_store.with_untracked(|store| store.contains(&account)) {
+            return Err(KeystoreError::InvalidSecret(
+                "This account already exists.".to_string(),
+            ));
+        }
+        account.encrypted_seed = Some(EncryptedSeed::encrypt(
+            &secret_key_string(&keypair),
+            password,
+        )?);
+        Ok(account)
+    };
+
+    let submit = move |e: SubmitEvent| {
+        e.prevent_default();
+        match import(&json(), &password()) {
+            Ok(account) => {
+                set_imported_address(Some(account.account_address.clone()));
+                set_account_store.update(move |store| store.accounts.push(account));
+                set_password(String::new());
+            }
+            Err(e) => set_error_message(e.to_string()),
+        }
+    };
+
+    view! {
+        <>
+            <Nav />
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <h1 class="text-2xl font-bold mb-4 text-center">Import JSON Account</h1>
+                {move || {
+                    if tauri_keystore::is_available() {
+                        view! {
+                            <p class="text-center">
+                                "JSON files can only be imported into the browser keystore."
+                            </p>
+                        }
+                            .into_any()
+                    } else if let Some(address) = imported_address() {
+                        view! {
+                            <div
+                                role="alert"
+                                class="max-w-sm mx-auto p-4 border-l-4 border-green-500 bg-green-100 text-green-800 rounded-xl dark:bg-green-900 dark:text-green-200 break-all"
+                            >
+                                {format!("Imported {address}")}
+                            </div>
+                        }
+                            .into_any()
+                    } else {
+                        view! {
+                            <form class="max-w-sm mx-auto" on:submit=submit>
+                                <div class="mb-5">
+                                    <label
+                                        for="json-file"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        JSON file
+                                    </label>
+                                    <input
+                                        type="file"
+                                        id="json-file"
+                                        accept=".json,application/json"
+                                        class="block w-full text-sm text-gray-900 dark:text-white"
+                                        required
+                                        on:change=read_file
+                                    />
+                                </div>
+                                <div class="mb-5">
+                                    <label
+                                        for="name"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        Account Name
+                                    </label>
+                                    <input
+                                        type="text"
+                                        id="name"
+                                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                        required
+                                        prop:value=move || name()
+                                        on:input=move |e| set_name(event_target_value(&e))
+                                    />
+                                </div>
+                                <div class="mb-5">
+                                    <label
+                                        for="password"
+                                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                                    >
+                                        Password of the file
+                                    </label>
+                                    <input
+                                        type="password"
+                                        id="password"
+                                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                        required
+                                        prop:value=move || password()
+                                        on:input=move |e| {
+                                            set_password(event_target_value(&e));
+                                            set_error_message(String::new());
+                                        }
+                                    />
+                                </div>
+                                {move || {
+                                    (!error_message().is_empty())
+                                        .then(|| {
+                                            view! {
+                                                <div
+                                                    role="alert"
+                                                    class="flex p-4 items-center gap-3 mb-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
+                                                >
+                                                    {error_message()}
+                                                </div>
+                                            }
+                                        })
+                                }}
+                                <button
+                                    type="submit"
+                                    class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                                >
+                                    Import
+                                </button>
+                            </form>
+                        }
+                            .into_any()
+                    }
+                }}
+            </main>
+        </>
+    }
+}
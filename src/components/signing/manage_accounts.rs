@@ -0,0 +1,349 @@
+use crate::components::navigation::nav::Nav;
+use crate::components::signing::accounts_store::AccountStore;
+use crate::services::account_backup::{restore_preview, AccountBackup, RestoreEntry};
+use crate::services::keystore::KeystoreError;
+use codee::string::JsonSerdeCodec;
+use gloo::file::{Blob, ObjectUrl};
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::storage::use_local_storage;
+
+fn status_view(status: ReadSignal<Option<Result<String, String>>>) -> impl IntoView {
+    move || {
+        status.get().map(|status| {
+            let (class, message) = match status {
+                Ok(message) => (
+                    "p-4 mt-4 border-l-4 border-green-500 bg-green-100 text-green-800 rounded-xl dark:bg-green-900 dark:text-green-200",
+                    message,
+                ),
+                Err(message) => (
+                    "p-4 mt-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl dark:bg-yellow-900 dark:text-yellow-200",
+                    message,
+                ),
+            };
+            view! {
+                <div role="alert" class=class>
+                    {message}
+                </div>
+            }
+        })
+    }
+}
+
+/// Renames accounts, changes the password of every local keystore entry at
+/// once, and backs up or restores the whole account store.
+#[component]
+pub fn ManageAccounts() -> impl IntoView {
+    let (account_store, set_account_store, _reset_account_store) =
+        use_local_storage::<AccountStore, JsonSerdeCodec>("account-store-state");
+
+    let (current_password, set_current_password) = signal(String::new());
+    let (new_password, set_new_password) = signal(String::new());
+    let (confirm_password, set_confirm_password) = signal(String::new());
+    let (password_status, set_password_status) = signal(None::<Result<String, String>>);
+
+    let (backup_password, set_backup_password) = signal(String::new());
+    let (backup_status, set_backup_status) = signal(None::<Result<String, String>>);
+    let (download, set_download) = signal(None::<(String, String)>);
+    // Revokes the previous blob URL when replaced or when the page is left.
+    let object_url = StoredValue::new_local(None::<ObjectUrl>);
+
+    let (restore_json, set_restore_json) = signal(String::new());
+    let (restore_password, set_restore_password) = signal(String::new());
+    let (preview, set_preview) = signal(None::<(AccountStore, Vec<RestoreEntry>)>);
+    let (restore_status, set_restore_status) = signal(None::<Result<String, String>>);
+
+    let change_password = move |e: SubmitEvent| {
+        e.prevent_default();
+        if new_password() != confirm_password() {
+            set_password_status(Some(Err("Passwords do not match.".to_string())));
+            return;
+        }
+        // Everything is re-encrypted first and written in a single update,
+        // so a wrong password leaves the store as it was.
+        let result = account_store
+            .with_untracked(|store| store.with_new_password(&current_password(), &new_password()));
+        match result {
+            Ok(store) => {
+                set_account_store.set(store);
+                set_current_password(String::new());
+                set_new_password(String::new());
+                set_confirm_password(String::new());
+                set_password_status(Some(Ok("Password changed.".to_string())));
+            }
+            Err(e) => set_password_status(Some(Err(e.to_string()))),
+        }
+    };
+
+    let create_backup = move |e: SubmitEvent| {
+        e.prevent_default();
+        set_download(None);
+        let result = account_store
+            .with_untracked(|store| AccountBackup::create(store, &backup_password()))
+            .and_then(|backup| backup.to_json());
+        match result {
+            Ok(json) => {
+                let url = ObjectUrl::from(Blob::new_with_options(
+                    json.as_str(),
+                    Some("application/json"),
+                ));
+                set_download(Some((
+                    url.to_string(),
+                    format!("shivarthu-accounts-{}.json", js_sys::Date::now() as u64),
+                )));
+                object_url.set_value(Some(url));
+                set_backup_password(String::new());
+                set_backup_status(None);
+            }
+            Err(e) => set_backup_status(Some(Err(e.to_string()))),
+        }
+    };
+
+    let read_backup_file = move |e: leptos::ev::Event| {
+        set_preview(None);
+        set_restore_status(None);
+        let input = event_target::<web_sys::HtmlInputElement>(&e);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            match gloo::file::futures::read_as_text(&gloo::file::File::from(file)).await {
+                Ok(text) => set_restore_json(text),
+                Err(e) => set_restore_status(Some(Err(format!("Cannot read the file: {e}")))),
+            }
+        });
+    };
+
+    let open_backup = move |e: SubmitEvent| {
+        e.prevent_default();
+        let result: Result<AccountStore, KeystoreError> = AccountBackup::parse(&restore_json())
+            .and_then(|backup| backup.open(&restore_password()));
+        match result {
+            Ok(backup_store) => {
+                let entries =
+                    account_store.with_untracked(|store| restore_preview(store, &backup_store));
+                set_preview(Some((backup_store, entries)));
+                set_restore_status(None);
+            }
+            Err(e) => set_restore_status(Some(Err(e.to_string()))),
+        }
+    };
+
+    let restore = move |_| {
+        let Some((backup_store, _)) = preview.get_untracked() else {
+            return;
+        };
+        let mut store = account_store.get_untracked();
+        let added = store.merge(backup_store);
+        set_account_store.set(store);
+        set_preview(None);
+        set_restore_password(String::new());
+        set_restore_status(Some(Ok(format!("Restored {added} accounts."))));
+    };
+
+    let account_rows = move || {
+        account_store
+            .get()
+            .accounts
+            .into_iter()
+            .map(|account| {
+                let (name, set_name) = signal(account.name.clone());
+                let address = account.account_address.clone();
+                let signer = account.signer.clone();
+                view! {
+                    <div class="flex flex-col sm:flex-row sm:items-center gap-2 p-2 border-b border-gray-300 dark:border-gray-600">
+                        <div class="flex-1">
+                            <input
+                                type="text"
+                                class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                                prop:value=name
+                                on:input=move |e| set_name(event_target_value(&e))
+                            />
+                            <p class="text-sm text-gray-500 dark:text-gray-400 break-all">
+                                {account.account_address.clone()}
+                            </p>
+                        </div>
+                        <button
+                            type="button"
+                            class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800 disabled:opacity-50"
+                            disabled=move || name.with(|n| n.trim().is_empty())
+                            on:click=move |_| {
+                                let new_name = name.get_untracked().trim().to_string();
+                                set_account_store
+                                    .update(|store| store.rename(&address, &signer, new_name));
+                            }
+                        >
+                            Rename
+                        </button>
+                    </div>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <>
+            <Nav />
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <h1 class="text-2xl font-bold mb-4 text-center">Manage Accounts</h1>
+
+                <h2 class="text-xl font-semibold mb-2">Accounts</h2>
+                <div class="mb-8">{account_rows}</div>
+
+                <h2 class="text-xl font-semibold mb-2">Change Password</h2>
+                <form class="max-w-sm mb-8" on:submit=change_password>
+                    <div class="mb-5">
+                        <label for="current-password" class="block mb-2 text-sm font-medium text-gray-900 dark:text-white">
+                            Current password
+                        </label>
+                        <input
+                            type="password"
+                            id="current-password"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            required
+                            prop:value=current_password
+                            on:input=move |e| set_current_password(event_target_value(&e))
+                        />
+                    </div>
+                    <div class="mb-5">
+                        <label for="new-password" class="block mb-2 text-sm font-medium text-gray-900 dark:text-white">
+                            New password
+                        </label>
+                        <input
+                            type="password"
+                            id="new-password"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            required
+                            prop:value=new_password
+                            on:input=move |e| set_new_password(event_target_value(&e))
+                        />
+                    </div>
+                    <div class="mb-5">
+                        <label for="confirm-new-password" class="block mb-2 text-sm font-medium text-gray-900 dark:text-white">
+                            Confirm new password
+                        </label>
+                        <input
+                            type="password"
+                            id="confirm-new-password"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            required
+                            prop:value=confirm_password
+                            on:input=move |e| set_confirm_password(event_target_value(&e))
+                        />
+                    </div>
+                    <button type="submit" class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800 disabled:opacity-50">
+                        Change Password
+                    </button>
+                    {status_view(password_status)}
+                </form>
+
+                <h2 class="text-xl font-semibold mb-2">Backup</h2>
+                <form class="max-w-sm mb-8" on:submit=create_backup>
+                    <div class="mb-5">
+                        <label for="backup-password" class="block mb-2 text-sm font-medium text-gray-900 dark:text-white">
+                            Backup password
+                        </label>
+                        <input
+                            type="password"
+                            id="backup-password"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            required
+                            prop:value=backup_password
+                            on:input=move |e| set_backup_password(event_target_value(&e))
+                        />
+                    </div>
+                    <button type="submit" class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800 disabled:opacity-50">
+                        Create Backup
+                    </button>
+                    {status_view(backup_status)}
+                    {move || {
+                        download()
+                            .map(|(href, file_name)| {
+                                view! {
+                                    <p class="mt-4">
+                                        <a
+                                            href=href
+                                            download=file_name.clone()
+                                            class="text-blue-600 underline dark:text-blue-400"
+                                        >
+                                            {format!("Download {file_name}")}
+                                        </a>
+                                    </p>
+                                }
+                            })
+                    }}
+                </form>
+
+                <h2 class="text-xl font-semibold mb-2">Restore</h2>
+                <form class="max-w-sm" on:submit=open_backup>
+                    <div class="mb-5">
+                        <label for="backup-file" class="block mb-2 text-sm font-medium text-gray-900 dark:text-white">
+                            Backup file
+                        </label>
+                        <input
+                            type="file"
+                            id="backup-file"
+                            accept=".json,application/json"
+                            class="block w-full text-sm text-gray-900 dark:text-white"
+                            required
+                            on:change=read_backup_file
+                        />
+                    </div>
+                    <div class="mb-5">
+                        <label for="restore-password" class="block mb-2 text-sm font-medium text-gray-900 dark:text-white">
+                            Backup password
+                        </label>
+                        <input
+                            type="password"
+                            id="restore-password"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            required
+                            prop:value=restore_password
+                            on:input=move |e| set_restore_password(event_target_value(&e))
+                        />
+                    </div>
+                    <button type="submit" class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800 disabled:opacity-50">
+                        Preview
+                    </button>
+                </form>
+                {move || {
+                    preview()
+                        .map(|(_, entries)| {
+                            let new_accounts = entries.iter().filter(|e| !e.duplicate).count();
+                            view! {
+                                <ul class="mt-4 space-y-1">
+                                    {entries
+                                        .into_iter()
+                                        .map(|entry| {
+                                            view! {
+                                                <li class="break-all">
+                                                    {format!(
+                                                        "{}: {}",
+                                                        entry.account.name,
+                                                        entry.account.account_address,
+                                                    )}
+                                                    {entry
+                                                        .duplicate
+                                                        .then_some(" (already saved, skipped)")}
+                                                </li>
+                                            }
+                                        })
+                                        .collect::<Vec<_>>()}
+                                </ul>
+                                <button
+                                    type="button"
+                                    class="mt-4 text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800 disabled:opacity-50"
+                                    disabled=new_accounts == 0
+                                    on:click=restore
+                                >
+                                    {format!("Restore {new_accounts} accounts")}
+                                </button>
+                            }
+                        })
+                }}
+                {status_view(restore_status)}
+            </main>
+        </>
+    }
+}
@@ -3,6 +3,16 @@ pub mod add_account;
 pub mod create_account;
 pub mod delete_account;
 pub mod enter_pass_with_nav;
+pub mod export_json_account;
+pub mod extension_accounts;
+pub mod import_json_account;
+pub mod manage_accounts;
 pub mod set_phrase_from_pass;
+pub mod sign_message;
 pub mod sign_out;
 pub mod sign_transaction_fn;
+pub mod tauri_keystore_accounts;
+pub mod transaction_progress;
+pub mod transaction_review;
+pub mod verify_message;
+pub mod watch_only_accounts;
@@ -1,11 +1,15 @@
 use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
 use crate::components::navigation::nav::Nav;
-use crate::components::signing::accounts_store::AccountStore;
+use crate::components::signing::accounts_store::{Account, AccountStore, UnlockedSeed};
+use crate::components::signing::extension_accounts::ExtensionAccounts;
+use crate::components::signing::tauri_keystore_accounts::TauriKeystoreAccounts;
+use crate::components::signing::watch_only_accounts::WatchOnlyAccounts;
+use crate::services::signer::SignerKind;
+use crate::services::tauri_keystore;
 use codee::string::JsonSerdeCodec;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 use leptos_use::storage::use_local_storage;
-use magic_crypt::{new_magic_crypt, MagicCryptTrait};
 use reactive_stores::Store;
 use serde::{Deserialize, Serialize};
 use subxt_core::utils::AccountId32;
@@ -29,12 +33,13 @@ pub fn SetPhraseFromPass() -> impl IntoView {
     let account = state.account_address();
     let mnemonic_phrase = state.mnemonic_phrase();
     let phase_exists_in_state = state.phase_exists_in_state();
-    let (account_store, _set_account_store, _reset_account_store) =
+    let signer_kind = state.signer_kind();
+    let crypto_type = state.crypto_type();
+    let (account_store, set_account_store, _reset_account_store) =
         use_local_storage::<AccountStore, JsonSerdeCodec>("account-store-state");
 
     let set_password_input = move |ev| {
         let password_string = event_target_value(&ev);
-        gloo::console::log!(password_string.clone());
         let result = if password_string.is_empty() {
             gloo::console::log!("String is empty");
 
@@ -44,20 +49,41 @@ pub fn SetPhraseFromPass() -> impl IntoView {
         };
         set_password(result);
     };
-    let handle_select_account = move |hash: String, address: String| {
-        let mc = new_magic_crypt!(password().unwrap(), 256);
-        match mc.decrypt_base64_to_string(&hash) {
-            Ok(seed) => {
-                *account.write() = address;
+    let handle_select_account = move |selected: Account| {
+        let Ok(password) = password() else {
+            return;
+        };
+        match selected.unlock(&password) {
+            Ok(UnlockedSeed { seed, migrated }) => {
+                if let Some(encrypted_seed) = migrated {
+                    set_account_store.update(|store| {
+                        store.save_migrated(&selected.account_address, encrypted_seed)
+                    });
+                }
+                *account.write() = selected.account_address;
                 *mnemonic_phrase.write() = Some(seed);
                 *phase_exists_in_state.write() = true;
+                *signer_kind.write() = SignerKind::LocalKeystore;
+                *crypto_type.write() = selected.crypto;
             }
             Err(e) => {
-                gloo::console::error!("Failed to decrypt seed:", e.to_string());
-                set_error("Failed to decrypt seed".to_string());
+                gloo::console::error!("Failed to unlock account:", e.to_string());
+                set_error(e.to_string());
             }
         }
     };
+
+    // The desktop build keeps seeds in the backend keystore instead.
+    if tauri_keystore::is_available() {
+        return view! {
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <TauriKeystoreAccounts />
+                <WatchOnlyAccounts />
+            </main>
+        }
+        .into_any();
+    }
+
     view! {
         <>
             <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
@@ -119,8 +145,9 @@ pub fn SetPhraseFromPass() -> impl IntoView {
                                                         store
                                                             .accounts
                                                             .iter()
+                                                            .filter(|a| a.signer == SignerKind::LocalKeystore)
                                                             .map(|a| {
-                                                                let hash = a.hash.clone();
+                                                                let selected = a.clone();
                                                                 let address = a.account_address.clone();
                                                                 let name = a.name.clone();
 
@@ -131,7 +158,7 @@ pub fn SetPhraseFromPass() -> impl IntoView {
                                                                             class="w-full text-left p-2 rounded-md hover:bg-blue-100 dark:hover:bg-gray-700 text-gray-900 dark:text-white"
                                                                             id={format!("account-{}", address.clone())}
                                                                             on:click=move |_| {
-                                                                                handle_select_account(hash.clone(), address.clone());
+                                                                                handle_select_account(selected.clone());
                                                                             }
                                                                         >
                                                                             {name.clone()}
@@ -155,8 +182,11 @@ pub fn SetPhraseFromPass() -> impl IntoView {
                             ().into_any()
                         }
                     }}
+                    <ExtensionAccounts />
+                    <WatchOnlyAccounts />
                 </div>
             </main>
         </>
     }
+    .into_any()
 }
@@ -0,0 +1,152 @@
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
+use crate::components::navigation::nav::Nav;
+use crate::services::error::AppError;
+use crate::services::signed_message::sign_message;
+use crate::services::signer::SignerKind;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos_icons::*;
+use leptos_use::{use_clipboard_with_options, UseClipboardOptions, UseClipboardReturn};
+use reactive_stores::Store;
+
+/// Signs a text with the signed-in account to prove off-chain that the
+/// signer controls the address.
+#[component]
+pub fn SignMessage() -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+    let account = state.account_address();
+    let mnemonic_phrase = state.mnemonic_phrase();
+    let phase_exists_in_state = state.phase_exists_in_state();
+    let signer_kind = state.signer_kind();
+    let crypto_type = state.crypto_type();
+    let (message, set_message) = signal(String::new());
+
+    let UseClipboardReturn { copied, copy, .. } =
+        use_clipboard_with_options(UseClipboardOptions::default());
+
+    let sign_action: Action<String, Result<String, AppError>, LocalStorage> =
+        Action::new_unsync(move |message: &String| {
+            let message = message.clone();
+            let signer_kind = signer_kind.get_untracked();
+            let address = account.get_untracked();
+            let secret = mnemonic_phrase.get_untracked();
+            let crypto_type = crypto_type.get_untracked();
+            let unlocked = phase_exists_in_state.get_untracked();
+            async move {
+                sign_message(
+                    &signer_kind,
+                    &address,
+                    secret.as_deref().map(String::as_str),
+                    crypto_type,
+                    unlocked,
+                    &message,
+                )
+                .await?
+                .to_json()
+            }
+        });
+    let pending = sign_action.pending();
+    let signed = sign_action.value();
+
+    let submit = move |e: SubmitEvent| {
+        e.prevent_default();
+        sign_action.dispatch(message.get_untracked());
+    };
+
+    view! {
+        <>
+            <Nav />
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <h1 class="text-2xl font-bold mb-4 text-center">Sign Message</h1>
+                <Show when=move || signer_kind.get() == SignerKind::WatchOnly>
+                    <div
+                        role="alert"
+                        class="max-w-sm mx-auto mb-4 p-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
+                    >
+                        "Watch-only accounts cannot sign. "
+                        <a href="/sign-in" class="underline">
+                            "Switch to a signing account"
+                        </a>
+                    </div>
+                </Show>
+                <form class="max-w-sm mx-auto" on:submit=submit>
+                    <p class="mb-4 text-sm text-gray-600 dark:text-gray-400 break-all">
+                        {move || {
+                            let address = account.get();
+                            if address.is_empty() {
+                                "Not signed in".to_string()
+                            } else {
+                                format!("Signing as {address}")
+                            }
+                        }}
+                    </p>
+                    <div class="mb-5">
+                        <label
+                            for="message"
+                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                        >
+                            Message
+                        </label>
+                        <textarea
+                            id="message"
+                            rows="4"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            required
+                            prop:value=move || message()
+                            on:input=move |e| set_message(event_target_value(&e))
+                        ></textarea>
+                    </div>
+                    <button
+                        type="submit"
+                        class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                        disabled=move || pending()
+                    >
+                        Sign
+                    </button>
+                </form>
+                {move || {
+                    signed()
+                        .map(|result| match result {
+                            Ok(json) => {
+                                view! {
+                                    <div class="max-w-sm mx-auto mt-6 flex flex-col gap-2">
+                                        <pre class="bg-gray-100 dark:bg-gray-800 p-4 rounded-md overflow-x-auto font-mono text-sm border border-gray-300 dark:border-gray-700">
+                                            {json.clone()}
+                                        </pre>
+                                        <button
+                                            on:click={
+                                                let copy = copy.clone();
+                                                move |_| copy(&json)
+                                            }
+                                            class="whitespace-nowrap self-end bg-blue-500 hover:bg-blue-600 dark:bg-blue-600 dark:hover:bg-blue-700 text-white font-semibold py-2 px-4 rounded transition"
+                                        >
+                                            <Show
+                                                when=copied
+                                                fallback=|| {
+                                                    view! { <Icon icon=icondata::AiCopyOutlined /> }
+                                                }
+                                            >
+                                                Copied!
+                                            </Show>
+                                        </button>
+                                    </div>
+                                }
+                                    .into_any()
+                            }
+                            Err(e) => {
+                                view! {
+                                    <div
+                                        role="alert"
+                                        class="max-w-sm mx-auto mt-6 flex p-4 items-center gap-3 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
+                                    >
+                                        {e.to_string()}
+                                    </div>
+                                }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </main>
+        </>
+    }
+}
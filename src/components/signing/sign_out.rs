@@ -1,5 +1,8 @@
 use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
 use crate::components::navigation::nav::Nav;
+use crate::services::account_secret::CryptoType;
+use crate::services::session::SessionManager;
+use crate::services::signer::SignerKind;
 use leptos::prelude::*;
 use reactive_stores::Store;
 
@@ -9,6 +12,9 @@ pub fn SignOut() -> impl IntoView {
     let account = state.account_address();
     let mnemonic_phrase = state.mnemonic_phrase();
     let phase_exists_in_state = state.phase_exists_in_state();
+    let signer_kind = state.signer_kind();
+    let crypto_type = state.crypto_type();
+    let session = expect_context::<SessionManager>();
     let navigate = leptos_router::hooks::use_navigate();
     view! {
         <>
@@ -18,9 +24,12 @@ pub fn SignOut() -> impl IntoView {
                     <button
                         class="px-4 py-2 bg-red-600 text-white font-semibold rounded-md hover:bg-red-700 focus:outline-none focus:ring-2 focus:ring-red-500"
                         on:click=move |_| {
+                            session.lock();
                             *account.write() = "".to_string();
                             *mnemonic_phrase.write() = None;
                             *phase_exists_in_state.write() = false;
+                            *signer_kind.write() = SignerKind::default();
+                            *crypto_type.write() = CryptoType::default();
                             navigate("/sign-in", Default::default());
 
                         }
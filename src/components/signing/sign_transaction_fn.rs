@@ -1,132 +1,194 @@
 use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
-use crate::components::common::spinner::LoadingSpinner;
 use crate::components::navigation::nav::Nav;
 use crate::components::signing::set_phrase_from_pass::SetPhraseFromPass;
-use crate::constants::constant::NODE_URL;
-use crate::services::common_services::polkadot;
+use crate::components::signing::transaction_progress::TransactionProgress;
+use crate::components::signing::transaction_review::TransactionReview;
+use crate::services::chain_client::ChainClient;
+use crate::services::error::AppError;
+use crate::services::signer::{
+    preview_transaction, AccountSigner, SignedTransaction, Signer, SignerKind,
+};
+use crate::services::tx_lifecycle::{follow_transaction, TxStage};
+use crate::services::tx_review::{dry_run, review_transaction, DryRunOutcome, TxReview};
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use reactive_stores::Store;
 use std::rc::Rc;
-use subxt::tx::TxStatus;
-use subxt::{OnlineClient, PolkadotConfig};
 use subxt_core::utils::AccountId32;
-use subxt_signer::{bip39::Mnemonic, sr25519::Keypair};
 
 #[derive(Clone, Copy, PartialEq)]
 enum TransactionState {
     Idle,
     SignIn,
+    Reviewing,
+    Review,
     Transaction,
 }
 
+/// Reviews `tx`, then signs it and dry-runs it while the review is shown, so
+/// the user sees whether it would succeed before confirming. Nothing is
+/// submitted until then. The signer holding the keypair only lives for the
+/// review, so locking the session leaves none behind. A lock before the
+/// transaction is submitted returns to the sign-in form, and the review
+/// starts again after unlock.
 #[component]
 pub fn SignTransactionFn(tx: Box<dyn subxt::tx::Payload>) -> impl IntoView {
     let (transaction_state, set_transaction_state) = signal(TransactionState::Idle);
     let state = expect_context::<Store<GlobalState>>();
-    let (run_bool, set_run_bool) = signal(true);
+    let chain_client = expect_context::<ChainClient>();
+    // Set when the session locked before the transaction was submitted.
+    let (interrupted, set_interrupted) = signal(false);
 
-    let (transaction_status, set_transaction_status) = signal(String::from(""));
-
-    let (transaction_event, set_transaction_event) = signal(String::from(""));
+    let (stage, set_stage) = signal(TxStage::Signing);
+    let (review, set_review) = signal::<Option<Result<TxReview, AppError>>>(None);
+    let (dry_run_outcome, set_dry_run_outcome) =
+        signal::<Option<Result<DryRunOutcome, AppError>>>(None);
+    // Bumped whenever a review is started or abandoned, so a review that
+    // finishes after the session locked is dropped.
+    let attempt = StoredValue::new(0u32);
+    // Signed for the dry run, and submitted once the user confirms.
+    let signed_tx = StoredValue::new_local(None::<SignedTransaction>);
 
     let account = state.account_address();
     let mnemonic_phrase = state.mnemonic_phrase();
     let phase_exists_in_state = state.phase_exists_in_state();
-    let tx = Rc::new(tx);
+    let signer_kind = state.signer_kind();
+    let crypto_type = state.crypto_type();
+    let tx = StoredValue::new_local(Rc::new(tx));
+
+    let current_signer = move || {
+        mnemonic_phrase.with_untracked(|phrase| {
+            AccountSigner::for_account(
+                &signer_kind.get_untracked(),
+                &account.get_untracked(),
+                phrase.as_deref().map(String::as_str),
+                crypto_type.get_untracked(),
+                phase_exists_in_state.get_untracked(),
+            )
+        })
+    };
+
+    let start_review = move |signer: Result<AccountSigner, AppError>| {
+        let review_attempt = attempt.get_value() + 1;
+        attempt.set_value(review_attempt);
+        set_interrupted(false);
+        signed_tx.set_value(None);
+        set_dry_run_outcome(None);
+        set_stage(TxStage::Signing);
+        set_transaction_state(TransactionState::Reviewing);
+        let tx = tx.get_value();
+        let crypto_type = crypto_type.get_untracked();
+        spawn_local(async move {
+            let signer = match signer {
+                Ok(signer) => signer,
+                Err(e) => {
+                    set_stage(TxStage::Error(e.to_string()));
+                    set_transaction_state(TransactionState::Transaction);
+                    return;
+                }
+            };
+            let review = async {
+                let api = chain_client.online().await?;
+                let account_id = signer.account_id();
+                let preview = preview_transaction(&api, &*tx, &account_id, crypto_type).await?;
+                let call_data = api.tx().call_data(&*tx)?;
+                review_transaction(&api, &preview, &call_data, account_id).await
+            };
+            let review = review.await;
+            if attempt.get_value() != review_attempt {
+                return;
+            }
+            set_review(Some(review));
+            set_transaction_state(TransactionState::Review);
+
+            // The runtime checks the signature before dispatching, so the
+            // dry run needs the signed extrinsic.
+            let signed = async {
+                let api = chain_client.online().await?;
+                let signed = signer.sign(&api, &*tx).await?;
+                let outcome = dry_run(&api, &signed).await?;
+                Ok::<_, AppError>((signed, outcome))
+            };
+            let signed = signed.await;
+            drop(signer);
+            if attempt.get_value() != review_attempt {
+                return;
+            }
+            match signed {
+                Ok((signed, outcome)) => {
+                    signed_tx.set_value(Some(signed));
+                    set_dry_run_outcome(Some(Ok(outcome)));
+                }
+                Err(e) => set_dry_run_outcome(Some(Err(e))),
+            }
+        });
+    };
+
+    // Back to the sign-in form, with a notice when a review was under way.
+    let require_unlock = move || {
+        attempt.update_value(|n| *n += 1);
+        signed_tx.set_value(None);
+        let state = transaction_state.get_untracked();
+        if matches!(
+            state,
+            TransactionState::Reviewing | TransactionState::Review
+        ) {
+            set_interrupted(true);
+        }
+        set_transaction_state(TransactionState::SignIn);
+    };
+
+    let submit_signed = move |signed: SignedTransaction| {
+        set_transaction_state(TransactionState::Transaction);
+        spawn_local(async move {
+            match signed.submit_and_watch().await {
+                Ok(progress) => follow_transaction(progress, set_stage).await,
+                Err(e) => set_stage(TxStage::Error(format!("Failed to submit transaction: {e}"))),
+            }
+        });
+    };
+
+    // Submits the extrinsic signed for the dry run. Without one, signing
+    // failed, so the review starts again.
+    let submit = move |_: ()| {
+        if let Some(signed) = signed_tx.try_update_value(Option::take).flatten() {
+            submit_signed(signed);
+            return;
+        }
+        match current_signer() {
+            Some(signer) => start_review(signer),
+            None => require_unlock(),
+        }
+    };
 
+    let cancel = move |_: ()| {
+        attempt.update_value(|n| *n += 1);
+        signed_tx.set_value(None);
+        set_stage(TxStage::Cancelled);
+        set_transaction_state(TransactionState::Transaction);
+    };
+
+    // Starts the review once a signer is available, and returns to the
+    // sign-in form when the session locks before the transaction is
+    // submitted. A submitted transaction keeps being followed.
     Effect::new(move |_| {
-        if let Some(phrase) = mnemonic_phrase.get() {
-            // Only run if run_bool is true
-            if run_bool.get() {
-                set_transaction_state.set(TransactionState::Transaction);
-                set_run_bool.set(false);
-                let tx_clone = Rc::clone(&tx);
-                spawn_local({
-                    let set_transaction_status = set_transaction_status.clone();
-                    let set_transaction_event = set_transaction_event.clone();
-
-                    async move {
-                        let mnemonic = Mnemonic::parse(phrase).unwrap();
-                        let keypair = Keypair::from_phrase(&mnemonic, None).unwrap();
-                        let api = OnlineClient::<PolkadotConfig>::from_url(NODE_URL).await;
-
-                        let api = match api {
-                            Ok(r) => r,
-                            Err(e) => {
-                                set_transaction_status(format!(
-                                    "Failed to submit transaction: {e}"
-                                ));
-                                return;
-                            }
-                        };
-
-                        let result = api
-                            .tx()
-                            .sign_and_submit_then_watch_default(&tx_clone, &keypair)
-                            .await;
-
-                        let mut result = match result {
-                            Ok(r) => r,
-                            Err(e) => {
-                                set_transaction_status(format!(
-                                    "Failed to submit transaction: {e}"
-                                ));
-                                return;
-                            }
-                        };
-
-                        while let Some(status) = result.next().await {
-                            match status.unwrap() {
-                                TxStatus::InFinalizedBlock(in_block) => {
-                                    let message = format!(
-                                        "Transaction {:?} is finalized in block {:?}",
-                                        in_block.extrinsic_hash(),
-                                        in_block.block_hash()
-                                    );
-                                    set_transaction_status(message);
-
-                                    let events = in_block.wait_for_success().await;
-
-                                    let events = match events {
-                                        Ok(r) => r,
-                                        Err(e) => {
-                                            set_transaction_status(format!("{e}"));
-                                            return;
-                                        }
-                                    };
-
-                                    let events_str = format!("{:?}", &events);
-                                    web_sys::console::log_1(&events_str.into());
-                                    for event in
-                                        events.find::<polkadot::system::events::ExtrinsicSuccess>()
-                                    {
-                                        web_sys::console::log_1(&format!("{:?}", event).into());
-                                    }
-
-                                    let success = events
-                                        .find_first::<polkadot::system::events::ExtrinsicSuccess>()
-                                        .unwrap();
-                                    match success {
-                                        Some(remark_event) => {
-                                            set_transaction_event(format!("{:?}", remark_event));
-                                        }
-                                        None => {
-                                            set_transaction_event("Transaction failed".to_string());
-                                        }
-                                    }
-                                }
-                                other => {
-                                    let message = format!("Status: {other:?}");
-                                    web_sys::console::log_1(&format!("{:?}", message).into());
-                                }
-                            }
-                        }
-                    }
-                });
+        signer_kind.track();
+        account.track();
+        mnemonic_phrase.track();
+        crypto_type.track();
+        phase_exists_in_state.track();
+
+        let state = transaction_state.get_untracked();
+        if state == TransactionState::Transaction {
+            return;
+        }
+        match current_signer() {
+            Some(signer) => {
+                if matches!(state, TransactionState::Idle | TransactionState::SignIn) {
+                    start_review(signer);
+                }
             }
-        } else {
-            set_transaction_state.set(TransactionState::SignIn);
+            None => require_unlock(),
         }
     });
 
@@ -141,59 +203,52 @@ pub fn SignTransactionFn(tx: Box<dyn subxt::tx::Payload>) -> impl IntoView {
 
                         view! {
                             <>
+                                <Show when=move || interrupted.get()>
+                                    <div
+                                        role="alert"
+                                        class="max-w-sm mx-auto mb-4 p-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
+                                    >
+                                        "The session locked before the transaction was submitted. Unlock again to review it."
+                                    </div>
+                                </Show>
+                                <Show when=move || signer_kind.get() == SignerKind::WatchOnly>
+                                    <div
+                                        role="alert"
+                                        class="max-w-sm mx-auto mb-4 p-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200 break-all"
+                                    >
+                                        {move || {
+                                            format!(
+                                                "{} is a watch-only account and cannot sign. Switch to a signing account below.",
+                                                account.get(),
+                                            )
+                                        }}
+                                    </div>
+                                </Show>
                                 <SetPhraseFromPass />
                             </>
                         }
                             .into_view()
                             .into_any()
                     }
-                    TransactionState::Transaction => {
-
-                        view! {
-                            <>
-                                {move || {
-                                    if !transaction_status().is_empty() {
-                                        view! {
-                                            <div
-                                                role="alert"
-                                                class="flex  p-4 items-center gap-3  mb-4 border-l-4 border-green-500 bg-green-100 text-green-800 rounded-xl shadow-md dark:bg-green-900 dark:text-green-200"
-                                            >
-                                                {transaction_status()}
-                                            </div>
-                                        }
-                                            .into_view()
-                                            .into_any()
-                                    } else {
-                                        view! {
-                                            <>
-                                                <LoadingSpinner />
-                                            </>
-                                        }
-                                            .into_view()
-                                            .into_any()
-                                    }
-                                }}
-                                {move || {
-                                    if !transaction_event().is_empty() {
-                                        view! {
-                                            <div
-                                                role="alert"
-                                                class="flex  p-4 items-center gap-3 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
-                                            >
-                                                {transaction_event()}
-                                            </div>
-                                        }
-                                            .into_view()
-                                            .into_any()
-                                    } else {
-                                        view! { <></> }.into_view().into_any()
-                                    }
-                                }}
-                            </>
-                        }
+                    TransactionState::Review => {
+                        review
+                            .get()
+                            .map(|review| {
+                                view! {
+                                    <TransactionReview
+                                        review=review
+                                        dry_run=dry_run_outcome
+                                        on_submit=submit
+                                        on_cancel=cancel
+                                    />
+                                }
+                            })
                             .into_view()
                             .into_any()
                     }
+                    TransactionState::Reviewing | TransactionState::Transaction => {
+                        view! { <TransactionProgress stage=stage /> }.into_view().into_any()
+                    }
                 }}
 
             </main>
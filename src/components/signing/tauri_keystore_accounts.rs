@@ -0,0 +1,117 @@
+use crate::components::common::error_view::AppErrorBoundary;
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
+use crate::services::error::AppError;
+use crate::services::signer::SignerKind;
+use crate::services::tauri_keystore::{self, KeystoreAccount};
+use leptos::prelude::*;
+use reactive_stores::Store;
+
+/// Unlocks an account held by the desktop backend. The seed stays in the
+/// backend, so only the address is put into the global state.
+#[component]
+pub fn TauriKeystoreAccounts() -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+    let account = state.account_address();
+    let mnemonic_phrase = state.mnemonic_phrase();
+    let phase_exists_in_state = state.phase_exists_in_state();
+    let signer_kind = state.signer_kind();
+    let crypto_type = state.crypto_type();
+    let (password, set_password) = signal(String::new());
+
+    let accounts = LocalResource::new(tauri_keystore::list_accounts);
+
+    // Accounts the backend already holds unlocked are selected without a password.
+    let unlock_action: Action<
+        (KeystoreAccount, String),
+        Result<KeystoreAccount, AppError>,
+        LocalStorage,
+    > = Action::new_unsync(|(selected, password): &(KeystoreAccount, String)| {
+        let selected = selected.clone();
+        let password = password.clone();
+        async move {
+            if !selected.unlocked {
+                tauri_keystore::unlock(&selected.address, &password).await?;
+            }
+            Ok(selected)
+        }
+    });
+    let pending = unlock_action.pending();
+    let unlock_result = unlock_action.value();
+
+    Effect::new(move |_| {
+        if let Some(Ok(selected)) = unlock_result.get() {
+            *account.write() = selected.address;
+            *mnemonic_phrase.write() = None;
+            *phase_exists_in_state.write() = true;
+            *signer_kind.write() = SignerKind::TauriKeystore;
+            *crypto_type.write() = selected.crypto_type;
+        }
+    });
+
+    let account_list = move || {
+        accounts.get().map(|result| {
+            result.map(|accounts| {
+                accounts
+                    .into_iter()
+                    .map(|keystore_account| {
+                        let KeystoreAccount {
+                            address,
+                            name,
+                            unlocked,
+                            ..
+                        } = keystore_account.clone();
+                        view! {
+                            <li>
+                                <button
+                                    type="button"
+                                    class="w-full text-left p-2 rounded-md hover:bg-blue-100 dark:hover:bg-gray-700 text-gray-900 dark:text-white"
+                                    id=format!("account-{}", address.clone())
+                                    disabled=move || password().is_empty() && !unlocked
+                                    on:click=move |_| {
+                                        unlock_action.dispatch((keystore_account.clone(), password()));
+                                    }
+                                >
+                                    {name.clone()}
+                                    :
+                                    {address.clone()}
+                                </button>
+                            </li>
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+
+    view! {
+        <div class="max-w-sm mx-auto">
+            <div class="mb-5">
+                <label
+                    for="password"
+                    class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                >
+                    Your password
+                </label>
+                <input
+                    type="password"
+                    id="password"
+                    class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                    prop:value=password
+                    on:input=move |e| set_password(event_target_value(&e))
+                />
+            </div>
+        </div>
+        <div class="mt-6 max-w-4xl mx-auto">
+            <h3 class="text-lg font-semibold mb-2 text-gray-900 dark:text-white">
+                Select Account
+            </h3>
+            <AppErrorBoundary on_retry=move |_: ()| accounts.refetch()>
+                <ul class="space-y-2">{account_list}</ul>
+            </AppErrorBoundary>
+            <p>{move || pending().then(|| "Unlocking...")}</p>
+            <AppErrorBoundary>
+                {move || unlock_result.get().map(|result| result.map(|_| ()))}
+            </AppErrorBoundary>
+        </div>
+    }
+}
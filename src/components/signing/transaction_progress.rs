@@ -0,0 +1,101 @@
+use crate::components::common::spinner::LoadingSpinner;
+use crate::services::tx_lifecycle::{TxOutcome, TxStage};
+use leptos::prelude::*;
+
+const STEPS: [&str; 4] = ["Validated", "Broadcast", "In best block", "Finalized"];
+
+fn alert(class: &'static str, message: String) -> AnyView {
+    view! {
+        <div
+            role="alert"
+            class=format!(
+                "flex p-4 items-center gap-3 mb-4 border-l-4 rounded-xl shadow-md {class}",
+            )
+        >
+            {message}
+        </div>
+    }
+    .into_any()
+}
+
+const SUCCESS: &str =
+    "border-green-500 bg-green-100 text-green-800 dark:bg-green-900 dark:text-green-200";
+const WARNING: &str =
+    "border-yellow-500 bg-yellow-100 text-yellow-800 dark:bg-yellow-900 dark:text-yellow-200";
+const FAILURE: &str = "border-red-500 bg-red-100 text-red-800 dark:bg-red-900 dark:text-red-200";
+
+/// Steps of a submitted extrinsic and the final result once it settles.
+#[component]
+pub fn TransactionProgress(stage: ReadSignal<TxStage>) -> impl IntoView {
+    let steps = move || {
+        let current = stage.with(TxStage::step);
+        STEPS
+            .iter()
+            .enumerate()
+            .map(|(index, label)| {
+                let class = if index < current {
+                    "text-green-700 dark:text-green-300 font-semibold"
+                } else {
+                    "text-gray-400 dark:text-gray-500"
+                };
+                view! { <li class=class>{*label}</li> }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let status = move || match stage.get() {
+        TxStage::Signing => view! { <LoadingSpinner /> }.into_any(),
+        TxStage::Validated | TxStage::Broadcast => {
+            alert(WARNING, "Waiting to be included in a block".to_string())
+        }
+        TxStage::InBestBlock { block_hash } => alert(
+            WARNING,
+            format!("Included in block {block_hash}, waiting for finality"),
+        ),
+        TxStage::NoLongerInBestBlock => alert(
+            WARNING,
+            "The block was retracted, waiting to be included again".to_string(),
+        ),
+        TxStage::Finalized {
+            block_hash,
+            extrinsic_hash,
+            outcome: TxOutcome::Success,
+        } => alert(
+            SUCCESS,
+            format!("Transaction {extrinsic_hash} is finalized in block {block_hash}"),
+        ),
+        TxStage::Finalized {
+            outcome: TxOutcome::Failed(failure),
+            ..
+        } => {
+            let message = match (failure.pallet, failure.variant) {
+                (Some(pallet), Some(variant)) => {
+                    format!(
+                        "Transaction failed: {} ({pallet}::{variant})",
+                        failure.message
+                    )
+                }
+                _ => format!("Transaction failed: {}", failure.message),
+            };
+            alert(FAILURE, message)
+        }
+        TxStage::Dropped(message) => alert(
+            FAILURE,
+            format!("Transaction dropped from the pool: {message}"),
+        ),
+        TxStage::Invalid(message) => alert(FAILURE, format!("Transaction is invalid: {message}")),
+        TxStage::Usurped(message) => alert(
+            FAILURE,
+            format!("Transaction was replaced by another with the same nonce: {message}"),
+        ),
+        TxStage::Error(message) => alert(FAILURE, message),
+        TxStage::Cancelled => alert(WARNING, "The transaction was not submitted".to_string()),
+    };
+
+    view! {
+        <div>
+            <ol class="flex justify-between mb-4 text-sm">{steps}</ol>
+            {status}
+        </div>
+    }
+}
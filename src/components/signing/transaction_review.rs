@@ -0,0 +1,178 @@
+use crate::services::error::AppError;
+use crate::services::tx_review::{DryRunOutcome, TxReview};
+use leptos::prelude::*;
+
+const SUCCESS: &str =
+    "border-green-500 bg-green-100 text-green-800 dark:bg-green-900 dark:text-green-200";
+const FAILURE: &str = "border-red-500 bg-red-100 text-red-800 dark:bg-red-900 dark:text-red-200";
+const PENDING: &str =
+    "border-blue-500 bg-blue-100 text-blue-800 dark:bg-blue-900 dark:text-blue-200";
+
+fn format_balance(amount: u128) -> String {
+    format!("{} SHIV", amount as f64 / 10f64.powi(10))
+}
+
+/// Style, message and whether the transaction should succeed, `None` while
+/// the dry run is still running.
+fn dry_run_status(
+    dry_run: Option<Result<DryRunOutcome, AppError>>,
+) -> (&'static str, String, Option<bool>) {
+    match dry_run {
+        None => (
+            PENDING,
+            "Signing the transaction for a dry run, approve it in your extension if asked. Nothing is submitted until you confirm here"
+                .to_string(),
+            None,
+        ),
+        Some(Ok(DryRunOutcome::Success)) => (
+            SUCCESS,
+            "The transaction should succeed".to_string(),
+            Some(true),
+        ),
+        Some(Ok(DryRunOutcome::DispatchFailed(failure))) => (
+            FAILURE,
+            match (&failure.pallet, &failure.variant) {
+                (Some(pallet), Some(variant)) => format!(
+                    "The transaction would fail and still be charged: {} ({pallet}::{variant})",
+                    failure.message
+                ),
+                _ => format!(
+                    "The transaction would fail and still be charged: {}",
+                    failure.message
+                ),
+            },
+            Some(false),
+        ),
+        Some(Ok(DryRunOutcome::Invalid(reason))) => (
+            FAILURE,
+            format!("The node would reject the transaction: {reason}"),
+            Some(false),
+        ),
+        Some(Err(e)) => (
+            FAILURE,
+            format!("Could not dry-run the transaction: {e}"),
+            Some(false),
+        ),
+    }
+}
+
+fn review_details(review: TxReview) -> (AnyView, bool) {
+    let balance_after_fee = review.balance_after_fee();
+    let can_pay = review.free_balance >= review.partial_fee;
+
+    let details = view! {
+        <h2 class="text-lg font-semibold">
+            {review.call.pallet.clone()}
+            "::"
+            {review.call.call.clone()}
+        </h2>
+        <table class="w-full text-sm text-left text-gray-700 dark:text-gray-300">
+            <tbody>
+                {review
+                    .call
+                    .args
+                    .iter()
+                    .map(|(name, value)| {
+                        view! {
+                            <tr class="border-b dark:border-gray-700">
+                                <th class="py-2 pr-4 font-medium">{name.clone()}</th>
+                                <td class="py-2 break-all">{value.clone()}</td>
+                            </tr>
+                        }
+                    })
+                    .collect::<Vec<_>>()}
+            </tbody>
+        </table>
+        <dl class="grid grid-cols-2 gap-2 text-sm">
+            <dt class="font-medium">Estimated fee</dt>
+            <dd id="transaction-fee">{format_balance(review.partial_fee)}</dd>
+            <dt class="font-medium">Free balance</dt>
+            <dd>{format_balance(review.free_balance)}</dd>
+            <dt class="font-medium">Free balance after fee</dt>
+            <dd>{format_balance(balance_after_fee)}</dd>
+        </dl>
+        {(!can_pay)
+            .then(|| {
+                view! {
+                    <div
+                        role="alert"
+                        class=format!("p-4 border-l-4 rounded-xl shadow-md {FAILURE}")
+                    >
+                        "The free balance does not cover the fee"
+                    </div>
+                }
+            })}
+    }
+    .into_any();
+    (details, can_pay)
+}
+
+/// Decoded call and fee of the transaction, followed by the dry-run result
+/// of the signed extrinsic. Submitting is enabled once the dry run is done;
+/// a failed one can still be submitted, and one that could not run is tried
+/// again. If the review itself failed the dry run is still shown.
+#[component]
+pub fn TransactionReview(
+    review: Result<TxReview, AppError>,
+    #[prop(into)] dry_run: Signal<Option<Result<DryRunOutcome, AppError>>>,
+    #[prop(into)] on_submit: Callback<()>,
+    #[prop(into)] on_cancel: Callback<()>,
+) -> impl IntoView {
+    let (details, can_pay) = match review {
+        Ok(review) => review_details(review),
+        Err(e) => (
+            view! {
+                <div role="alert" class=format!("p-4 border-l-4 rounded-xl shadow-md {FAILURE}")>
+                    {format!("Could not review the transaction: {e}")}
+                </div>
+            }
+            .into_any(),
+            false,
+        ),
+    };
+    let status = Memo::new(move |_| dry_run_status(dry_run.get()));
+    let submit_label = move || {
+        if dry_run.with(|dry_run| matches!(dry_run, Some(Err(_)))) {
+            return "Try again";
+        }
+        match status.with(|(_, _, will_succeed)| *will_succeed) {
+            None => "Dry-running...",
+            Some(true) if can_pay => "Submit",
+            Some(_) => "Submit anyway",
+        }
+    };
+
+    view! {
+        <div class="space-y-4">
+            {details}
+            <div
+                role="alert"
+                id="transaction-dry-run"
+                class=move || {
+                    status.with(|(class, _, _)| format!("p-4 border-l-4 rounded-xl shadow-md {class}"))
+                }
+            >
+                {move || status.with(|(_, message, _)| message.clone())}
+            </div>
+            <div class="flex gap-3">
+                <button
+                    type="button"
+                    id="transaction-submit"
+                    class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800 disabled:opacity-50"
+                    disabled=move || dry_run.with(Option::is_none)
+                    on:click=move |_| on_submit.run(())
+                >
+                    {submit_label}
+                </button>
+                <button
+                    type="button"
+                    id="transaction-cancel"
+                    class="text-gray-900 bg-white border border-gray-300 hover:bg-gray-100 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-gray-800 dark:text-white dark:border-gray-600 dark:hover:bg-gray-700"
+                    on:click=move |_| on_cancel.run(())
+                >
+                    Cancel
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,208 @@
+use crate::components::navigation::nav::Nav;
+use crate::services::error::AppError;
+use crate::services::signed_message::SignedMessage;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos_icons::*;
+use leptos_use::{use_clipboard_with_options, UseClipboardOptions, UseClipboardReturn};
+use serde::Serialize;
+
+/// Outcome of a check, copied as JSON to share it along with the message.
+#[derive(Serialize)]
+struct Verification<'a> {
+    #[serde(flatten)]
+    signed: &'a SignedMessage,
+    valid: bool,
+}
+
+/// Checks that a message was signed by an address. The JSON produced by the
+/// sign page can be pasted to fill the fields.
+#[component]
+pub fn VerifyMessage() -> impl IntoView {
+    let (address, set_address) = signal(String::new());
+    let (message, set_message) = signal(String::new());
+    let (signature, set_signature) = signal(String::new());
+    let (error_message, set_error_message) = signal(String::new());
+    let (result, set_result) = signal(None::<(bool, String)>);
+
+    let UseClipboardReturn { copied, copy, .. } =
+        use_clipboard_with_options(UseClipboardOptions::default());
+
+    let paste_json = move |e: leptos::ev::Event| {
+        set_result(None);
+        match SignedMessage::parse(&event_target_value(&e)) {
+            Ok(signed) => {
+                set_address(signed.address);
+                set_message(signed.message);
+                set_signature(signed.signature);
+                set_error_message(String::new());
+            }
+            Err(e) => set_error_message(e.to_string()),
+        }
+    };
+
+    let verify = move |e: SubmitEvent| {
+        e.prevent_default();
+        let signed = SignedMessage {
+            address: address().trim().to_string(),
+            message: message(),
+            signature: signature().trim().to_string(),
+        };
+        let outcome = signed.verify().and_then(|valid| {
+            serde_json::to_string_pretty(&Verification {
+                signed: &signed,
+                valid,
+            })
+            .map(|json| (valid, json))
+            .map_err(|e| AppError::Validation(e.to_string()))
+        });
+        match outcome {
+            Ok(outcome) => {
+                set_error_message(String::new());
+                set_result(Some(outcome));
+            }
+            Err(e) => {
+                set_result(None);
+                set_error_message(e.to_string());
+            }
+        }
+    };
+
+    let text_input = move |id: &'static str,
+                           label: &'static str,
+                           value: ReadSignal<String>,
+                           set_value: WriteSignal<String>| {
+        view! {
+            <div class="mb-5">
+                <label for=id class="block mb-2 text-sm font-medium text-gray-900 dark:text-white">
+                    {label}
+                </label>
+                <input
+                    type="text"
+                    id=id
+                    class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                    required
+                    prop:value=move || value()
+                    on:input=move |e| {
+                        set_value(event_target_value(&e));
+                        set_result(None);
+                    }
+                />
+            </div>
+        }
+    };
+
+    view! {
+        <>
+            <Nav />
+            <main class="p-6 max-w-4xl mx-auto bg-white dark:bg-gray-900 text-gray-900 dark:text-gray-100 min-h-screen transition-colors duration-300">
+                <h1 class="text-2xl font-bold mb-4 text-center">Verify Message</h1>
+                <form class="max-w-sm mx-auto" on:submit=verify>
+                    <div class="mb-5">
+                        <label
+                            for="signed-json"
+                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                        >
+                            Signed message JSON (optional)
+                        </label>
+                        <textarea
+                            id="signed-json"
+                            rows="4"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            on:change=paste_json
+                        ></textarea>
+                    </div>
+                    {text_input("address", "Address", address, set_address)}
+                    <div class="mb-5">
+                        <label
+                            for="message"
+                            class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                        >
+                            Message
+                        </label>
+                        <textarea
+                            id="message"
+                            rows="4"
+                            class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                            required
+                            prop:value=move || message()
+                            on:input=move |e| {
+                                set_message(event_target_value(&e));
+                                set_result(None);
+                            }
+                        ></textarea>
+                    </div>
+                    {text_input("signature", "Signature", signature, set_signature)}
+                    {move || {
+                        (!error_message().is_empty())
+                            .then(|| {
+                                view! {
+                                    <div
+                                        role="alert"
+                                        class="flex p-4 items-center gap-3 mb-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
+                                    >
+                                        {error_message()}
+                                    </div>
+                                }
+                            })
+                    }}
+                    <button
+                        type="submit"
+                        class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                    >
+                        Verify
+                    </button>
+                </form>
+                {move || {
+                    result()
+                        .map(|(valid, json)| {
+                            view! {
+                                <div class="max-w-sm mx-auto mt-6 flex flex-col gap-2">
+                                    {if valid {
+                                        view! {
+                                            <div
+                                                role="alert"
+                                                class="flex items-center gap-3 p-4 border-l-4 border-green-500 bg-green-100 text-green-800 rounded-xl shadow-md"
+                                            >
+                                                <p>The signature is valid for this address</p>
+                                            </div>
+                                        }
+                                            .into_any()
+                                    } else {
+                                        view! {
+                                            <div
+                                                role="alert"
+                                                class="flex items-center gap-3 p-4 border-l-4 border-red-500 bg-red-100 text-red-800 rounded-xl shadow-md"
+                                            >
+                                                <p>The signature does not match this address and message</p>
+                                            </div>
+                                        }
+                                            .into_any()
+                                    }}
+                                    <pre class="bg-gray-100 dark:bg-gray-800 p-4 rounded-md overflow-x-auto font-mono text-sm border border-gray-300 dark:border-gray-700">
+                                        {json.clone()}
+                                    </pre>
+                                    <button
+                                        on:click={
+                                            let copy = copy.clone();
+                                            move |_| copy(&json)
+                                        }
+                                        class="whitespace-nowrap self-end bg-blue-500 hover:bg-blue-600 dark:bg-blue-600 dark:hover:bg-blue-700 text-white font-semibold py-2 px-4 rounded transition"
+                                    >
+                                        <Show
+                                            when=copied
+                                            fallback=|| {
+                                                view! { <Icon icon=icondata::AiCopyOutlined /> }
+                                            }
+                                        >
+                                            Copied!
+                                        </Show>
+                                    </button>
+                                </div>
+                            }
+                        })
+                }}
+            </main>
+        </>
+    }
+}
@@ -0,0 +1,162 @@
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
+use crate::components::signing::accounts_store::{Account, AccountStore};
+use crate::services::account_secret::CryptoType;
+use crate::services::common_services::account_id_from_str;
+use crate::services::signer::SignerKind;
+use codee::string::JsonSerdeCodec;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos_use::storage::use_local_storage;
+use reactive_stores::Store;
+
+/// Follows an account by its address alone. Balance, stakes and game views
+/// work as for any other account, while signing is refused.
+#[component]
+pub fn WatchOnlyAccounts() -> impl IntoView {
+    let state = expect_context::<Store<GlobalState>>();
+    let account = state.account_address();
+    let mnemonic_phrase = state.mnemonic_phrase();
+    let phase_exists_in_state = state.phase_exists_in_state();
+    let signer_kind = state.signer_kind();
+    let crypto_type = state.crypto_type();
+    let (account_store, set_account_store, _reset_account_store) =
+        use_local_storage::<AccountStore, JsonSerdeCodec>("account-store-state");
+    let (address, set_address) = signal(String::new());
+    let (name, set_name) = signal(String::new());
+    let (error_message, set_error_message) = signal(String::new());
+
+    let handle_select_account = move |address: String| {
+        *account.write() = address;
+        *mnemonic_phrase.write() = None;
+        *phase_exists_in_state.write() = false;
+        *signer_kind.write() = SignerKind::WatchOnly;
+        *crypto_type.write() = CryptoType::default();
+    };
+
+    let add = move |e: SubmitEvent| {
+        e.prevent_default();
+        let account_address = match account_id_from_str(address().trim()) {
+            Ok(account_id) => account_id.to_string(),
+            Err(e) => {
+                set_error_message(e.to_string());
+                return;
+            }
+        };
+        let watched = Account {
+            hash: String::new(),
+            encrypted_seed: None,
+            account_address: account_address.clone(),
+            name: name().trim().to_string(),
+            signer: SignerKind::WatchOnly,
+            crypto: CryptoType::default(),
+        };
+        if account_store.with_untracked(|store| store.contains(&watched)) {
+            set_error_message("This address is already watched.".to_string());
+            return;
+        }
+        set_account_store.update(move |store| store.accounts.push(watched));
+        set_address(String::new());
+        set_name(String::new());
+        handle_select_account(account_address);
+    };
+
+    let saved_accounts = move || {
+        account_store.with(|store| {
+            store
+                .accounts
+                .iter()
+                .filter(|a| a.signer == SignerKind::WatchOnly)
+                .map(|a| {
+                    let address = a.account_address.clone();
+                    let name = a.name.clone();
+                    view! {
+                        <li>
+                            <button
+                                type="button"
+                                class="w-full text-left p-2 rounded-md hover:bg-blue-100 dark:hover:bg-gray-700 text-gray-900 dark:text-white"
+                                id=format!("watch-only-account-{}", address.clone())
+                                on:click={
+                                    let address = address.clone();
+                                    move |_| handle_select_account(address.clone())
+                                }
+                            >
+                                {name.clone()}
+                                :
+                                {address.clone()}
+                                <span class="ml-2 text-xs text-gray-500 dark:text-gray-400">
+                                    watch-only
+                                </span>
+                            </button>
+                        </li>
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    view! {
+        <div class="mt-6 max-w-4xl mx-auto">
+            <h3 class="text-lg font-semibold mb-2 text-gray-900 dark:text-white">
+                Watch-only
+            </h3>
+            <ul class="space-y-2">{saved_accounts}</ul>
+            <form class="max-w-sm mt-2" on:submit=add>
+                <div class="mb-5">
+                    <label
+                        for="watch-only-address"
+                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                    >
+                        Address
+                    </label>
+                    <input
+                        type="text"
+                        id="watch-only-address"
+                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                        required
+                        prop:value=move || address()
+                        on:input=move |e| {
+                            set_address(event_target_value(&e));
+                            set_error_message(String::new());
+                        }
+                    />
+                </div>
+                <div class="mb-5">
+                    <label
+                        for="watch-only-name"
+                        class="block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+                    >
+                        Name
+                    </label>
+                    <input
+                        type="text"
+                        id="watch-only-name"
+                        class="bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+                        required
+                        prop:value=move || name()
+                        on:input=move |e| set_name(event_target_value(&e))
+                    />
+                </div>
+                {move || {
+                    (!error_message().is_empty())
+                        .then(|| {
+                            view! {
+                                <div
+                                    role="alert"
+                                    class="flex p-4 items-center gap-3 mb-4 border-l-4 border-yellow-500 bg-yellow-100 text-yellow-800 rounded-xl shadow-md dark:bg-yellow-900 dark:text-yellow-200"
+                                >
+                                    {error_message()}
+                                </div>
+                            }
+                        })
+                }}
+                <button
+                    type="submit"
+                    id="add-watch-only"
+                    class="text-white bg-blue-700 hover:bg-blue-800 focus:ring-4 focus:outline-none focus:ring-blue-300 font-medium rounded-lg text-sm w-full sm:w-auto px-5 py-2.5 text-center dark:bg-blue-600 dark:hover:bg-blue-700 dark:focus:ring-blue-800"
+                >
+                    Watch address
+                </button>
+            </form>
+        </div>
+    }
+}
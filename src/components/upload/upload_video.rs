@@ -0,0 +1,54 @@
+use crate::components::api::ipfs_request::ipfs_call;
+use crate::components::api::select_ipfs_provider::DEFAULT_IPFS_PROVIDER;
+use leptos::html;
+use leptos::prelude::*;
+use web_sys::File;
+
+/// File input that uploads the chosen file to IPFS and writes its cid to
+/// `set_cid_props`.
+#[component]
+pub fn FileUpload(set_cid_props: WriteSignal<String>, accept_file_type: String) -> impl IntoView {
+    let input_element: NodeRef<html::Input> = NodeRef::new();
+    let (cid, set_cid) = signal(String::from(""));
+
+    let upload_action: Action<File, ()> = Action::new_unsync(move |file: &File| {
+        let file = file.clone();
+        async move {
+            let name = file.name();
+            let response = ipfs_call(DEFAULT_IPFS_PROVIDER, file, name).await;
+            set_cid(response.clone());
+            set_cid_props(response);
+        }
+    });
+    let pending = upload_action.pending();
+
+    let on_change = move |_| {
+        let file = input_element
+            .get()
+            .and_then(|input| input.files())
+            .and_then(|files| files.get(0));
+        if let Some(file) = file {
+            upload_action.dispatch(file);
+        }
+    };
+
+    view! {
+        <div>
+            <input
+                type="file"
+                accept=accept_file_type
+                node_ref=input_element
+                on:change=on_change
+                class="block w-full text-sm text-gray-900 border border-gray-300 rounded-lg cursor-pointer bg-gray-50 dark:text-gray-400 focus:outline-none dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400"
+            />
+            <p class="mt-1 text-sm text-gray-500 dark:text-gray-300">
+                {move || pending().then(|| "Uploading...")}
+            </p>
+            <p class="mt-1 text-sm text-gray-500 dark:text-gray-300 break-all">
+                {move || {
+                    if cid().is_empty() { String::new() } else { format!("Uploaded: {}", cid()) }
+                }}
+            </p>
+        </div>
+    }
+}
@@ -1,9 +1,37 @@
 use crate::components::signing::add_account::AddAccount;
 use crate::components::signing::create_account::CreateAccount;
 use crate::components::signing::enter_pass_with_nav::EnterPassWithNav;
+use crate::components::signing::export_json_account::ExportJsonAccount;
+use crate::components::signing::import_json_account::ImportJsonAccount;
+use crate::components::signing::manage_accounts::ManageAccounts;
+use crate::components::signing::sign_message::SignMessage;
+use crate::components::signing::verify_message::VerifyMessage;
 
 use crate::components::common_transaction::balance_transfer::BalanceTransfer;
+use crate::components::schelling_game::common::juror_dashboard::JurorDashboard;
+use crate::components::schelling_game::common::vote_vault::ManageVoteVault;
+use crate::components::schelling_game::department_funding::apply_staking_period_params::ApplyStakingPeriodParams as DepartmentFundingApplyStakingPeriod;
+use crate::components::schelling_game::department_funding::create_department_fund::CreateDepartmentFund;
+use crate::components::schelling_game::department_funding::game::schelling_game::SchellingGame as DepartmentFundingGame;
+use crate::components::schelling_game::department_funding::views::juror_selected_check::JurorSelectedCheck as DepartmentFundingJurorSelected;
+use crate::components::schelling_game::departments::add_member_to_department_params::AddMemberToDepartmentParams;
+use crate::components::schelling_game::departments::create_department::CreateDepartment;
+use crate::components::schelling_game::departments::remove_member_from_department_params::RemoveMemberFromDepartmentParams;
+use crate::components::schelling_game::positive_externality::apply_staking_period_params::ApplyStakingPeriodParams as PositiveExternalityApplyStakingPeriod;
 use crate::components::schelling_game::positive_externality::create_post::CreatePositiveExternalityPost;
+use crate::components::schelling_game::positive_externality::game::schelling_game::SchellingGame as PositiveExternalityGame;
+use crate::components::schelling_game::positive_externality::views::juror_selected_check::JurorSelectedCheck as PositiveExternalityJurorSelected;
+use crate::components::schelling_game::profile_validation::add_profile::AddProfile;
+use crate::components::schelling_game::profile_validation::add_profile_stake::AddProfileStake;
+use crate::components::schelling_game::profile_validation::game::schelling_game::SchellingGame as ProfileValidationGame;
+use crate::components::schelling_game::profile_validation::view_profile_from_address::ViewProfileFromAddress;
+use crate::components::schelling_game::profile_validation::views::juror_selected_check::JurorSelectedCheck as ProfileValidationJurorSelected;
+use crate::components::schelling_game::project_tips::apply_staking_period_params::ApplyStakingPeriodParams as ProjectTipsApplyStakingPeriod;
+use crate::components::schelling_game::project_tips::create_project::CreateProject;
+use crate::components::schelling_game::project_tips::game::schelling_game::SchellingGame as ProjectTipsGame;
+use crate::components::schelling_game::project_tips::views::juror_selected_check::JurorSelectedCheck as ProjectTipsJurorSelected;
+use crate::components::settings::node_endpoints::NodeEndpoints;
+use crate::components::settings::session_settings::SessionSettingsForm;
 use crate::components::shared_storage::address_submission::AddressSubmission;
 use crate::components::signing::delete_account::DeleteAccount;
 use crate::components::signing::sign_out::SignOut;
@@ -21,14 +49,97 @@ pub fn RouterApp() -> impl IntoView {
                 <Route path=path!("/create-account") view=CreateAccount />
                 <Route path=path!("/sign-in") view=EnterPassWithNav />
                 <Route path=path!("/add-account") view=AddAccount />
+                <Route path=path!("/import-json-account") view=ImportJsonAccount />
+                <Route path=path!("/export-json-account") view=ExportJsonAccount />
                 <Route
                     path=path!("/work-wins/create-post")
                     view=CreatePositiveExternalityPost
                 />
                 <Route path=path!("/balance-transfer") view=BalanceTransfer />
                 <Route path=path!("/delete-account") view=DeleteAccount />
+                <Route path=path!("/manage-accounts") view=ManageAccounts />
+                <Route path=path!("/sign-message") view=SignMessage />
+                <Route path=path!("/verify-message") view=VerifyMessage />
+                <Route path=path!("/vote-vault") view=ManageVoteVault />
+                <Route path=path!("/juror-dashboard") view=JurorDashboard />
                 <Route path=path!("/sign-out") view=SignOut />
                 <Route path=path!("/address-submission") view=AddressSubmission />
+                <Route path=path!("/settings/endpoints") view=NodeEndpoints />
+                <Route path=path!("/settings/session") view=SessionSettingsForm />
+                <Route
+                    path=path!("/positive-externality/schelling-game/:user_to_calculate")
+                    view=PositiveExternalityGame
+                />
+                <Route
+                    path=path!("/positive-externality/apply-staking-period/:user_to_calculate")
+                    view=PositiveExternalityApplyStakingPeriod
+                />
+                <Route
+                    path=path!("/positive-externality/juror-selected-check/:user_to_calculate")
+                    view=PositiveExternalityJurorSelected
+                />
+                <Route path=path!("/profile-validation/add-profile") view=AddProfile />
+                <Route
+                    path=path!("/profile-validation/add-profile-stake/:profile_user_account")
+                    view=AddProfileStake
+                />
+                <Route
+                    path=path!("/profile-validation/view-profile/:profile_user_account")
+                    view=ViewProfileFromAddress
+                />
+                <Route
+                    path=path!("/profile-validation/schelling-game/:profile_user_account")
+                    view=ProfileValidationGame
+                />
+                <Route
+                    path=path!("/profile-validation/juror-selected-check/:profile_user_account")
+                    view=ProfileValidationJurorSelected
+                />
+                <Route
+                    path=path!("/project-tips/create-project/:department_id")
+                    view=CreateProject
+                />
+                <Route
+                    path=path!("/project-tips/schelling-game/:project_id")
+                    view=ProjectTipsGame
+                />
+                <Route
+                    path=path!("/project-tips/apply-staking-period/:project_id")
+                    view=ProjectTipsApplyStakingPeriod
+                />
+                <Route
+                    path=path!("/project-tips/juror-selected-check/:project_id")
+                    view=ProjectTipsJurorSelected
+                />
+                <Route
+                    path=path!("/department-funding/create-department-fund/:department_id")
+                    view=CreateDepartmentFund
+                />
+                <Route
+                    path=path!("/department-funding/schelling-game/:department_required_fund_id")
+                    view=DepartmentFundingGame
+                />
+                <Route
+                    path=path!(
+                        "/department-funding/apply-staking-period/:department_required_fund_id"
+                    )
+                    view=DepartmentFundingApplyStakingPeriod
+                />
+                <Route
+                    path=path!(
+                        "/department-funding/juror-selected-check/:department_required_fund_id"
+                    )
+                    view=DepartmentFundingJurorSelected
+                />
+                <Route path=path!("/departments/create-department") view=CreateDepartment />
+                <Route
+                    path=path!("/departments/add-member/:department_id")
+                    view=AddMemberToDepartmentParams
+                />
+                <Route
+                    path=path!("/departments/remove-member/:department_id")
+                    view=RemoveMemberFromDepartmentParams
+                />
             </Routes>
         </Router>
     }
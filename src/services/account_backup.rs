@@ -0,0 +1,148 @@
+use crate::components::signing::accounts_store::{Account, AccountStore};
+use crate::services::keystore::{EncryptedSeed, KeystoreError};
+use serde::{Deserialize, Serialize};
+
+/// Version written by [`AccountBackup::create`].
+pub const BACKUP_VERSION: u8 = 1;
+
+/// Whole `AccountStore` in one file, encrypted the same way as a single
+/// seed. Entries inside keep their own encryption as well.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AccountBackup {
+    pub version: u8,
+    pub created_at_ms: u64,
+    pub data: EncryptedSeed,
+}
+
+/// One account of a backup and whether the store already has it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestoreEntry {
+    pub account: Account,
+    pub duplicate: bool,
+}
+
+impl AccountBackup {
+    pub fn create(store: &AccountStore, password: &str) -> Result<Self, KeystoreError> {
+        Self::seal(store, password, js_sys::Date::now() as u64)
+    }
+
+    fn seal(
+        store: &AccountStore,
+        password: &str,
+        created_at_ms: u64,
+    ) -> Result<Self, KeystoreError> {
+        let json =
+            serde_json::to_string(store).map_err(|e| KeystoreError::Encryption(e.to_string()))?;
+        Ok(Self {
+            version: BACKUP_VERSION,
+            created_at_ms,
+            data: EncryptedSeed::encrypt(&json, password)?,
+        })
+    }
+
+    pub fn parse(json: &str) -> Result<Self, KeystoreError> {
+        serde_json::from_str(json)
+            .map_err(|e| KeystoreError::Corrupted(format!("Not an account backup: {e}")))
+    }
+
+    pub fn to_json(&self) -> Result<String, KeystoreError> {
+        serde_json::to_string_pretty(self).map_err(|e| KeystoreError::Encryption(e.to_string()))
+    }
+
+    pub fn open(&self, password: &str) -> Result<AccountStore, KeystoreError> {
+        if self.version != BACKUP_VERSION {
+            return Err(KeystoreError::UnsupportedVersion(self.version));
+        }
+        let json = self.data.decrypt(password)?;
+        serde_json::from_str(&json)
+            .map_err(|e| KeystoreError::Corrupted(format!("Invalid backup content: {e}")))
+    }
+}
+
+/// Accounts of `backup` marked as duplicates when `current` already holds
+/// the same address with the same signer.
+pub fn restore_preview(current: &AccountStore, backup: &AccountStore) -> Vec<RestoreEntry> {
+    backup
+        .accounts
+        .iter()
+        .map(|account| RestoreEntry {
+            duplicate: current.contains(account),
+            account: account.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::account_secret::CryptoType;
+    use crate::services::signer::SignerKind;
+
+    fn account(account_address: &str, signer: SignerKind) -> Account {
+        Account {
+            hash: String::new(),
+            encrypted_seed: Some(
+                EncryptedSeed::encrypt("seed of the account", "account password").unwrap(),
+            ),
+            account_address: account_address.to_string(),
+            name: "Alice".to_string(),
+            signer,
+            crypto: CryptoType::Sr25519,
+        }
+    }
+
+    fn store() -> AccountStore {
+        AccountStore {
+            accounts: vec![
+                account(
+                    "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+                    SignerKind::LocalKeystore,
+                ),
+                account(
+                    "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+                    SignerKind::TauriKeystore,
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn opens_an_exported_backup() {
+        let store = store();
+        let json = AccountBackup::seal(&store, "backup password", 1_700_000_000_000)
+            .unwrap()
+            .to_json()
+            .unwrap();
+        let backup = AccountBackup::parse(&json).unwrap();
+        assert_eq!(backup.created_at_ms, 1_700_000_000_000);
+        assert_eq!(backup.open("backup password").unwrap(), store);
+    }
+
+    #[test]
+    fn rejects_a_wrong_password() {
+        let backup = AccountBackup::seal(&store(), "backup password", 0).unwrap();
+        assert_eq!(
+            backup.open("account password").unwrap_err(),
+            KeystoreError::WrongPassword
+        );
+    }
+
+    #[test]
+    fn rejects_an_unknown_version() {
+        let mut backup = AccountBackup::seal(&store(), "backup password", 0).unwrap();
+        backup.version = BACKUP_VERSION + 1;
+        assert_eq!(
+            backup.open("backup password").unwrap_err(),
+            KeystoreError::UnsupportedVersion(BACKUP_VERSION + 1)
+        );
+    }
+
+    #[test]
+    fn marks_accounts_with_the_same_signer_as_duplicates() {
+        let mut current = store();
+        current.accounts.truncate(1);
+        let preview = restore_preview(&current, &store());
+        assert!(preview[0].duplicate);
+        assert!(!preview[1].duplicate);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::services::keystore::{random_bytes, KeystoreError};
+use ed25519_dalek::{Signer as _, SigningKey};
+use schnorrkel::derive::{ChainCode, Derivation};
+use schnorrkel::{signing_context, ExpansionMode, Keypair, MiniSecretKey, SecretKey};
+use serde::{Deserialize, Serialize};
+use shivarthu_keys::seed::{ed25519_seed, mini_secret_seed};
+use sp_core_hashing::blake2_256;
+use std::str::FromStr;
+use subxt::utils::{AccountId32, MultiSignature};
+use subxt_signer::bip39::Mnemonic;
+use subxt_signer::{ecdsa, DeriveJunction, ExposeSecret, SecretUri};
+
+/// Length of an sr25519 secret key in the ed25519 compatible form used by
+/// polkadot.js: 32 bytes of key followed by 32 bytes of nonce.
+pub const SECRET_KEY_LEN: usize = 64;
+
+/// Mnemonic lengths accepted when creating or importing an account.
+pub const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// Signing context of sr25519 signatures on Substrate chains.
+pub(crate) const SIGNING_CONTEXT: &[u8] = b"substrate";
+
+/// Key scheme of an account. Accounts saved before it was recorded are
+/// sr25519.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub enum CryptoType {
+    #[default]
+    Sr25519,
+    Ed25519,
+    Ecdsa,
+}
+
+impl CryptoType {
+    pub const ALL: [CryptoType; 3] = [CryptoType::Sr25519, CryptoType::Ed25519, CryptoType::Ecdsa];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CryptoType::Sr25519 => "sr25519",
+            CryptoType::Ed25519 => "ed25519",
+            CryptoType::Ecdsa => "ecdsa",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        CryptoType::ALL
+            .into_iter()
+            .find(|crypto| crypto.label() == label)
+    }
+}
+
+/// Keypair of an unlocked account in any of the supported schemes.
+pub enum AccountKeypair {
+    Sr25519(Keypair),
+    Ed25519(SigningKey),
+    Ecdsa(ecdsa::Keypair),
+}
+
+impl AccountKeypair {
+    /// Derives the keypair of a stored secret, see [`keypair_from_secret`].
+    /// Raw secret keys from JSON keystores are sr25519 only, and ed25519
+    /// and ecdsa only have hard derivation.
+    pub fn from_secret(crypto: CryptoType, secret: &str) -> Result<Self, KeystoreError> {
+        match crypto {
+            CryptoType::Sr25519 => keypair_from_secret(secret).map(AccountKeypair::Sr25519),
+            CryptoType::Ed25519 => Ok(AccountKeypair::Ed25519(SigningKey::from_bytes(
+                &ed25519_seed(&parse_uri(secret)?)?,
+            ))),
+            CryptoType::Ecdsa => ecdsa::Keypair::from_uri(&parse_uri(secret)?)
+                .map(AccountKeypair::Ecdsa)
+                .map_err(|e| KeystoreError::InvalidSecret(e.to_string())),
+        }
+    }
+
+    /// ecdsa accounts are the blake2 hash of the compressed public key.
+    pub fn account_id(&self) -> AccountId32 {
+        match self {
+            AccountKeypair::Sr25519(keypair) => AccountId32(keypair.public.to_bytes()),
+            AccountKeypair::Ed25519(signing_key) => {
+                AccountId32(signing_key.verifying_key().to_bytes())
+            }
+            AccountKeypair::Ecdsa(keypair) => AccountId32(blake2_256(&keypair.public_key().0)),
+        }
+    }
+
+    pub fn sign(&self, message: &[u8]) -> MultiSignature {
+        match self {
+            AccountKeypair::Sr25519(keypair) => MultiSignature::Sr25519(
+                keypair
+                    .sign(signing_context(SIGNING_CONTEXT).bytes(message))
+                    .to_bytes(),
+            ),
+            AccountKeypair::Ed25519(signing_key) => {
+                MultiSignature::Ed25519(signing_key.sign(message).to_bytes())
+            }
+            AccountKeypair::Ecdsa(keypair) => MultiSignature::Ecdsa(keypair.sign(message).0),
+        }
+    }
+}
+
+/// New English seed phrase of `words` words, from 4 bytes of entropy for
+/// every 3 words.
+pub fn generate_phrase(words: usize) -> Result<String, KeystoreError> {
+    if !WORD_COUNTS.contains(&words) {
+        return Err(KeystoreError::InvalidSecret(format!(
+            "A seed phrase has 12, 15, 18, 21 or 24 words, not {words}"
+        )));
+    }
+    let entropy = random_bytes::<32>()?;
+    let mnemonic = Mnemonic::from_entropy(&entropy[..words * 4 / 3])
+        .map_err(|e| KeystoreError::InvalidSecret(e.to_string()))?;
+    Ok(mnemonic.to_string())
+}
+
+/// Derives the keypair behind a stored account secret.
+///
+/// The local keystore holds either a secret URI, `phrase//hard/soft///password`,
+/// or, for accounts imported from a JSON keystore, the `0x` prefixed hex of
+/// the raw secret key.
+pub fn keypair_from_secret(secret: &str) -> Result<Keypair, KeystoreError> {
+    if let Some(hex_key) = secret.strip_prefix("0x") {
+        if hex_key.len() == SECRET_KEY_LEN * 2 {
+            let bytes = hex::decode(hex_key)
+                .map_err(|e| KeystoreError::InvalidSecret(format!("Invalid secret key: {e}")))?;
+            return keypair_from_secret_key(&bytes);
+        }
+    }
+
+    let uri = parse_uri(secret)?;
+    let keypair = keypair_from_phrase(uri.phrase.expose_secret(), uri_password(&uri))?;
+    Ok(derive(keypair, &uri.junctions))
+}
+
+fn parse_uri(secret: &str) -> Result<SecretUri, KeystoreError> {
+    SecretUri::from_str(secret)
+        .map_err(|e| KeystoreError::InvalidSecret(format!("Invalid secret URI: {e}")))
+}
+
+fn uri_password(uri: &SecretUri) -> Option<&str> {
+    match &uri.password {
+        Some(password) => Some(password.expose_secret()),
+        None => None,
+    }
+}
+
+/// Secret URI of `phrase` with an optional derivation path such as
+/// `//juror/1` and an optional BIP39 password. `phrase` may also be a `0x`
+/// prefixed 32 byte seed, as exported by key management tools.
+pub fn secret_uri(
+    phrase: &str,
+    derivation_path: &str,
+    password: &str,
+) -> Result<String, KeystoreError> {
+    let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+    let word_count = phrase.split(' ').count();
+    if phrase.starts_with("0x") {
+        if phrase.len() != 66 {
+            return Err(KeystoreError::InvalidSecret(
+                "A hex seed has 32 bytes".to_string(),
+            ));
+        }
+    } else if !WORD_COUNTS.contains(&word_count) {
+        return Err(KeystoreError::InvalidSecret(format!(
+            "A seed phrase has 12, 15, 18, 21 or 24 words, not {word_count}"
+        )));
+    }
+    let derivation_path = derivation_path.trim();
+    if !derivation_path.is_empty()
+        && (!derivation_path.starts_with('/') || derivation_path.contains("///"))
+    {
+        return Err(KeystoreError::InvalidSecret(
+            "Derivation path must look like //hard/soft".to_string(),
+        ));
+    }
+    let mut uri = format!("{phrase}{derivation_path}");
+    if !password.is_empty() {
+        uri.push_str("///");
+        uri.push_str(password);
+    }
+    Ok(uri)
+}
+
+/// Same derivation as `Keypair::from_phrase` in subxt-signer and
+/// substrate-bip39, so addresses do not change. A `0x` prefixed phrase is
+/// taken as a 32 byte seed.
+pub fn keypair_from_phrase(phrase: &str, password: Option<&str>) -> Result<Keypair, KeystoreError> {
+    let mini_secret = MiniSecretKey::from_bytes(&*mini_secret_seed(phrase, password)?)
+        .map_err(|e| KeystoreError::InvalidSecret(e.to_string()))?;
+    Ok(mini_secret.expand_to_keypair(ExpansionMode::Ed25519))
+}
+
+/// Substrate sr25519 derivation: hard junctions go through a mini secret
+/// key, soft ones derive the secret key directly.
+fn derive(keypair: Keypair, junctions: &[DeriveJunction]) -> Keypair {
+    junctions
+        .iter()
+        .fold(keypair.secret.clone(), |secret, junction| {
+            let chain_code = ChainCode(junction.inner());
+            if junction.is_hard() {
+                secret
+                    .hard_derive_mini_secret_key(Some(chain_code), b"")
+                    .0
+                    .expand(ExpansionMode::Ed25519)
+            } else {
+                secret.derived_key_simple(chain_code, b"").0
+            }
+        })
+        .to_keypair()
+}
+
+pub fn keypair_from_secret_key(bytes: &[u8]) -> Result<Keypair, KeystoreError> {
+    if bytes.len() != SECRET_KEY_LEN {
+        return Err(KeystoreError::InvalidSecret(format!(
+            "Secret key must be {SECRET_KEY_LEN} bytes"
+        )));
+    }
+    let secret = SecretKey::from_ed25519_bytes(bytes)
+        .map_err(|e| KeystoreError::InvalidSecret(e.to_string()))?;
+    Ok(secret.to_keypair())
+}
+
+/// Secret string stored for an account that only came with a raw key.
+pub fn secret_key_string(keypair: &Keypair) -> String {
+    format!("0x{}", hex::encode(keypair.secret.to_ed25519_bytes()))
+}
@@ -0,0 +1,217 @@
+use crate::services::endpoints::{endpoint_health, EndpointStore};
+use crate::services::error::AppError;
+use crate::services::shivarthu_rpc::ShivarthuRpc;
+use futures::future::{select, Either};
+use futures::pin_mut;
+use gloo::timers::future::TimeoutFuture;
+use jsonrpsee_core::client::Client;
+use jsonrpsee_wasm_client::WasmClientBuilder;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use std::sync::Arc;
+use subxt::backend::rpc::RpcClient;
+use subxt::{OnlineClient, PolkadotConfig};
+
+const INITIAL_BACKOFF_MS: u32 = 500;
+const MAX_BACKOFF_MS: u32 = 30_000;
+const WAIT_FOR_CONNECTION_MS: u32 = 100;
+const WATCH_ACTIVE_ENDPOINT_MS: u32 = 500;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConnectionStatus {
+    Connecting,
+    Connected,
+    Reconnecting { attempt: u32, retry_in_ms: u32 },
+}
+
+#[derive(Clone)]
+struct Connection {
+    online: OnlineClient<PolkadotConfig>,
+    rpc: Arc<Client>,
+}
+
+/// One websocket to the node, shared by every component through context.
+///
+/// The jsonrpsee client on that socket serves the custom Shivarthu RPC
+/// methods and backs the subxt client used for storage and extrinsics. The
+/// endpoint comes from the persisted [`EndpointStore`]; when it drops, the
+/// next healthy endpoint is selected, and if none answers the whole list is
+/// retried with exponential backoff.
+#[derive(Clone, Copy)]
+pub struct ChainClient {
+    connection: RwSignal<Option<Connection>, LocalStorage>,
+    status: RwSignal<ConnectionStatus>,
+    connected_url: RwSignal<Option<String>>,
+    endpoint_store: Signal<EndpointStore>,
+    set_endpoint_store: WriteSignal<EndpointStore>,
+}
+
+impl ChainClient {
+    pub fn new(
+        endpoint_store: Signal<EndpointStore>,
+        set_endpoint_store: WriteSignal<EndpointStore>,
+    ) -> Self {
+        let chain_client = Self {
+            connection: RwSignal::new_local(None),
+            status: RwSignal::new(ConnectionStatus::Connecting),
+            connected_url: RwSignal::new(None),
+            endpoint_store,
+            set_endpoint_store,
+        };
+        chain_client.spawn_connection_loop();
+        chain_client
+    }
+
+    pub fn status(&self) -> ReadSignal<ConnectionStatus> {
+        self.status.read_only()
+    }
+
+    pub fn connected_url(&self) -> ReadSignal<Option<String>> {
+        self.connected_url.read_only()
+    }
+
+    /// Waits until the node is reachable and returns the shared subxt client.
+    pub async fn online(&self) -> Result<OnlineClient<PolkadotConfig>, AppError> {
+        Ok(self.connection().await?.online)
+    }
+
+    /// Waits until the node is reachable and returns the shared jsonrpsee client.
+    pub async fn rpc(&self) -> Result<Arc<Client>, AppError> {
+        Ok(self.connection().await?.rpc)
+    }
+
+    /// Typed client for the custom Shivarthu RPC methods.
+    pub async fn shivarthu_rpc(&self) -> Result<ShivarthuRpc, AppError> {
+        Ok(ShivarthuRpc::new(self.rpc().await?))
+    }
+
+    /// Waits while endpoints are being tried, and fails once the whole
+    /// failover list has been tried without success.
+    async fn connection(&self) -> Result<Connection, AppError> {
+        loop {
+            if let Some(connection) = self.connection.get_untracked() {
+                return Ok(connection);
+            }
+            if let ConnectionStatus::Reconnecting { .. } = self.status.get_untracked() {
+                return Err(AppError::Connection(
+                    "No node endpoint is reachable".to_string(),
+                ));
+            }
+            TimeoutFuture::new(WAIT_FOR_CONNECTION_MS).await;
+        }
+    }
+
+    fn spawn_connection_loop(self) {
+        spawn_local(async move {
+            let mut attempt = 0;
+            loop {
+                if let Some((url, connection)) = self.connect_first_healthy().await {
+                    attempt = 0;
+                    let rpc = Arc::clone(&connection.rpc);
+                    self.connection.set(Some(connection));
+                    self.connected_url.set(Some(url.clone()));
+                    self.status.set(ConnectionStatus::Connected);
+
+                    let disconnected = rpc.on_disconnect();
+                    let switched = self.wait_for_endpoint_switch(&url);
+                    pin_mut!(disconnected, switched);
+                    if let Either::Left(_) = select(disconnected, switched).await {
+                        gloo::console::warn!("Lost connection to node:", url.clone());
+                        self.skip_endpoint(&url);
+                    }
+                    self.connection.set(None);
+                    self.connected_url.set(None);
+                    self.status.set(ConnectionStatus::Connecting);
+                    continue;
+                }
+
+                attempt += 1;
+                let retry_in_ms = backoff_ms(attempt);
+                self.status.set(ConnectionStatus::Reconnecting {
+                    attempt,
+                    retry_in_ms,
+                });
+                TimeoutFuture::new(retry_in_ms).await;
+            }
+        });
+    }
+
+    /// Tries the endpoints in failover order and connects to the first one
+    /// that passes a health check, marking it as the active endpoint.
+    async fn connect_first_healthy(&self) -> Option<(String, Connection)> {
+        let urls = self
+            .endpoint_store
+            .with_untracked(|store| store.failover_order());
+        for url in urls {
+            match connect(&url).await {
+                Ok(connection) => {
+                    if self
+                        .endpoint_store
+                        .with_untracked(|store| store.active_url != url)
+                    {
+                        let active_url = url.clone();
+                        self.set_endpoint_store
+                            .update(move |store| store.active_url = active_url);
+                    }
+                    return Some((url, connection));
+                }
+                Err(e) => {
+                    gloo::console::warn!("Endpoint unhealthy:", url, e.to_string());
+                }
+            }
+        }
+        None
+    }
+
+    /// Moves the active endpoint past `url` so the next attempt starts with
+    /// the following endpoint in the list.
+    fn skip_endpoint(&self, url: &str) {
+        let next_url = self.endpoint_store.with_untracked(|store| {
+            store
+                .endpoints
+                .iter()
+                .position(|e| e.url == url)
+                .and_then(|index| store.endpoints.get((index + 1) % store.endpoints.len()))
+                .map(|e| e.url.clone())
+        });
+        if let Some(next_url) = next_url {
+            self.set_endpoint_store
+                .update(move |store| store.active_url = next_url);
+        }
+    }
+
+    /// Resolves once the user picks a different active endpoint.
+    async fn wait_for_endpoint_switch(&self, url: &str) {
+        loop {
+            if self
+                .endpoint_store
+                .with_untracked(|store| store.active_url != url)
+            {
+                return;
+            }
+            TimeoutFuture::new(WATCH_ACTIVE_ENDPOINT_MS).await;
+        }
+    }
+}
+
+/// Opens the websocket to `url`, checks the node's health over it and builds
+/// the subxt client on the same socket.
+async fn connect(url: &str) -> Result<Connection, AppError> {
+    let rpc = Arc::new(
+        WasmClientBuilder::default()
+            .build(url)
+            .await
+            .map_err(|e| AppError::Connection(e.to_string()))?,
+    );
+    endpoint_health(&rpc).await?;
+    let online = OnlineClient::<PolkadotConfig>::from_rpc_client(RpcClient::new(Arc::clone(&rpc)))
+        .await
+        .map_err(|e| AppError::Connection(e.to_string()))?;
+    Ok(Connection { online, rpc })
+}
+
+fn backoff_ms(attempt: u32) -> u32 {
+    INITIAL_BACKOFF_MS
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(MAX_BACKOFF_MS)
+}
@@ -0,0 +1,113 @@
+use crate::services::chain_client::ChainClient;
+use crate::services::common_services::polkadot;
+use crate::services::error::AppError;
+use gloo::timers::future::TimeoutFuture;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+const RESUBSCRIBE_MS: u32 = 2_000;
+const CLOCK_TICK_MS: u32 = 1_000;
+/// Used until the runtime constant has been read.
+const DEFAULT_BLOCK_TIME_MS: u64 = 6_000;
+
+/// Latest finalized block and the local time it was seen at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct FinalizedHead {
+    number: u32,
+    seen_at_ms: f64,
+}
+
+/// Estimated wall-clock end of a period ending at some block.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlockDeadline {
+    pub blocks_left: u32,
+    pub deadline_ms: f64,
+}
+
+/// Finalized-head subscription shared by every component through context.
+///
+/// Components that depend on the current block track [`ChainHead::current_block`]
+/// instead of polling, and countdowns read [`ChainHead::now_ms`], a single
+/// one-second clock, so only the displayed time changes between blocks.
+#[derive(Clone, Copy)]
+pub struct ChainHead {
+    head: RwSignal<Option<FinalizedHead>>,
+    current_block: Memo<Option<u32>>,
+    block_time_ms: RwSignal<u64>,
+    now_ms: RwSignal<f64>,
+}
+
+impl ChainHead {
+    pub fn new(chain_client: ChainClient) -> Self {
+        let head = RwSignal::new(None::<FinalizedHead>);
+        let chain_head = Self {
+            head,
+            current_block: Memo::new(move |_| head.get().map(|head| head.number)),
+            block_time_ms: RwSignal::new(DEFAULT_BLOCK_TIME_MS),
+            now_ms: RwSignal::new(js_sys::Date::now()),
+        };
+        chain_head.spawn_subscription(chain_client);
+        chain_head.spawn_clock();
+        chain_head
+    }
+
+    /// Number of the latest finalized block, `None` until the first one arrives.
+    pub fn current_block(&self) -> Memo<Option<u32>> {
+        self.current_block
+    }
+
+    /// Local time in milliseconds, updated every second.
+    pub fn now_ms(&self) -> ReadSignal<f64> {
+        self.now_ms.read_only()
+    }
+
+    /// Estimates when `end_block` is finalized from the time the current head
+    /// arrived and the runtime block time.
+    pub fn deadline(&self, end_block: u32) -> Option<BlockDeadline> {
+        let head = self.head.get()?;
+        let blocks_left = end_block.saturating_sub(head.number);
+        Some(BlockDeadline {
+            blocks_left,
+            deadline_ms: head.seen_at_ms + (blocks_left as u64 * self.block_time_ms.get()) as f64,
+        })
+    }
+
+    fn spawn_subscription(self, chain_client: ChainClient) {
+        spawn_local(async move {
+            loop {
+                if let Err(e) = self.follow_finalized(chain_client).await {
+                    gloo::console::warn!("Finalized head subscription failed:", e.to_string());
+                }
+                TimeoutFuture::new(RESUBSCRIBE_MS).await;
+            }
+        });
+    }
+
+    async fn follow_finalized(&self, chain_client: ChainClient) -> Result<(), AppError> {
+        let api = chain_client.online().await?;
+        let minimum_period = api
+            .constants()
+            .at(&polkadot::constants().timestamp().minimum_period())?;
+        // A slot lasts twice the minimum period between timestamps.
+        self.block_time_ms.set(minimum_period.saturating_mul(2));
+
+        let mut blocks = api.blocks().subscribe_finalized().await?;
+        while let Some(block) = blocks.next().await {
+            let block = block?;
+            self.head.set(Some(FinalizedHead {
+                number: block.number(),
+                seen_at_ms: js_sys::Date::now(),
+            }));
+        }
+        Ok(())
+    }
+
+    fn spawn_clock(self) {
+        spawn_local(async move {
+            loop {
+                TimeoutFuture::new(CLOCK_TICK_MS).await;
+                self.now_ms.set(js_sys::Date::now());
+            }
+        });
+    }
+}
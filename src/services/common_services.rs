@@ -1,11 +1,16 @@
-use crate::services::error::ErrorString;
+use crate::services::error::AppError;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::str::FromStr;
 use subxt::ext::codec::{Compact, Encode};
-use subxt::utils::Era;
+use subxt::utils::{AccountId32, Era};
 use subxt::{self, OnlineClient, PolkadotConfig};
+use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::JsFuture;
 
+/// Name shown by wallet extensions when the app asks for access.
+const EXTENSION_APP_NAME: &str = "Shivarthu";
+
 #[subxt::subxt(
     runtime_metadata_path = "./artifacts/metadata.scale",
     derive_for_all_types = "Clone, Debug, Eq, PartialEq"
@@ -34,3 +39,227 @@ pub(crate) async fn fetch_events_dynamically() -> Result<Vec<String>, subxt::Err
     }
     Ok(event_strings)
 }
+
+/// Parses an SS58 address, reporting a malformed one as a validation error.
+pub fn account_id_from_str(address: &str) -> Result<AccountId32, AppError> {
+    AccountId32::from_str(address)
+        .map_err(|e| AppError::Validation(format!("Invalid account address {address}: {e}")))
+}
+
+/// Account exposed by a browser wallet extension through `window.injectedWeb3`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InjectedAccount {
+    pub address: String,
+    pub name: Option<String>,
+    pub source: String,
+}
+
+fn js_error(e: JsValue) -> AppError {
+    AppError::Signer(e.as_string().unwrap_or_else(|| format!("{e:?}")))
+}
+
+fn js_get(target: &JsValue, key: &str) -> Result<JsValue, AppError> {
+    js_sys::Reflect::get(target, &JsValue::from_str(key)).map_err(js_error)
+}
+
+/// Calls `target[method](...args)` and awaits the returned promise.
+async fn js_call_async(
+    target: &JsValue,
+    method: &str,
+    args: &js_sys::Array,
+) -> Result<JsValue, AppError> {
+    let function: js_sys::Function = js_get(target, method)?
+        .dyn_into()
+        .map_err(|_| AppError::Signer(format!("`{method}` is not a function")))?;
+    let promise: js_sys::Promise = function
+        .apply(target, args)
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(|_| AppError::Signer(format!("`{method}` did not return a promise")))?;
+    JsFuture::from(promise).await.map_err(js_error)
+}
+
+/// Enables the extension registered under `source` and returns its injected API.
+async fn enable_extension(source: &str) -> Result<JsValue, AppError> {
+    let injected_web3 = js_get(&js_sys::global(), "injectedWeb3")?;
+    let extension = if injected_web3.is_undefined() {
+        JsValue::UNDEFINED
+    } else {
+        js_get(&injected_web3, source)?
+    };
+    if extension.is_undefined() {
+        return Err(AppError::Signer(format!(
+            "Wallet extension {source} is not installed"
+        )));
+    }
+    js_call_async(
+        &extension,
+        "enable",
+        &js_sys::Array::of1(&JsValue::from_str(EXTENSION_APP_NAME)),
+    )
+    .await
+}
+
+/// Names of the installed wallet extensions, e.g. `polkadot-js` or `talisman`.
+pub fn extension_sources() -> Result<Vec<String>, AppError> {
+    let injected_web3 = js_get(&js_sys::global(), "injectedWeb3")?;
+    if injected_web3.is_undefined() {
+        return Ok(Vec::new());
+    }
+    Ok(js_sys::Object::keys(injected_web3.unchecked_ref())
+        .iter()
+        .filter_map(|source| source.as_string())
+        .collect())
+}
+
+/// Accounts of every installed extension that granted access.
+///
+/// An extension that refuses access is skipped; its error is only returned
+/// when no extension provided any account.
+pub async fn get_accounts() -> Result<Vec<InjectedAccount>, AppError> {
+    let sources = extension_sources()?;
+    if sources.is_empty() {
+        return Err(AppError::Signer(
+            "No wallet extension found in this browser".to_string(),
+        ));
+    }
+
+    let mut accounts = Vec::new();
+    let mut last_error = None;
+    for source in sources {
+        let result: Result<(), AppError> = async {
+            let injected = enable_extension(&source).await?;
+            let list = js_call_async(
+                &js_get(&injected, "accounts")?,
+                "get",
+                &js_sys::Array::new(),
+            )
+            .await?;
+            for account in js_sys::Array::from(&list).iter() {
+                let address = js_get(&account, "address")?
+                    .as_string()
+                    .ok_or_else(|| AppError::Signer("Account without address".to_string()))?;
+                accounts.push(InjectedAccount {
+                    address,
+                    name: js_get(&account, "name")?.as_string(),
+                    source: source.clone(),
+                });
+            }
+            Ok(())
+        }
+        .await;
+        if let Err(e) = result {
+            gloo::console::warn!(format!("{source}: {e}"));
+            last_error = Some(e);
+        }
+    }
+
+    match last_error {
+        Some(e) if accounts.is_empty() => Err(e),
+        _ => Ok(accounts),
+    }
+}
+
+/// Asks the extension to sign an immortal extrinsic with `call_data` and
+/// returns the SCALE encoded `MultiSignature`.
+pub async fn extension_signature_for_extrinsic(
+    call_data: &[u8],
+    api: &OnlineClient<PolkadotConfig>,
+    account_nonce: u64,
+    account_source: &str,
+    account_address: &str,
+) -> Result<Vec<u8>, AppError> {
+    let genesis_hash = encode_then_hex(&api.genesis_hash());
+    // These numbers aren't SCALE encoded; their bytes are just converted to hex:
+    let spec_version = to_hex(&api.runtime_version().spec_version.to_be_bytes());
+    let transaction_version = to_hex(&api.runtime_version().transaction_version.to_be_bytes());
+    let nonce = to_hex(&account_nonce.to_be_bytes());
+    // An immortal transaction is checked against the genesis hash.
+    let mortality_checkpoint = encode_then_hex(&api.genesis_hash());
+    let era = encode_then_hex(&Era::Immortal);
+    let method = to_hex(call_data);
+    let signed_extensions: Vec<String> = api
+        .metadata()
+        .extrinsic()
+        .transaction_extensions_by_version(0)
+        .map(|extensions| {
+            extensions
+                .map(|extension| extension.identifier().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    let tip = encode_then_hex(&Compact(0u128));
+
+    let payload = json!({
+        "specVersion": spec_version,
+        "transactionVersion": transaction_version,
+        "address": account_address,
+        "blockHash": mortality_checkpoint,
+        "blockNumber": "0x00000000",
+        "era": era,
+        "genesisHash": genesis_hash,
+        "method": method,
+        "nonce": nonce,
+        "signedExtensions": signed_extensions,
+        "tip": tip,
+        "version": 4,
+    });
+
+    let injected = enable_extension(account_source).await?;
+    let payload = js_sys::JSON::parse(&payload.to_string()).map_err(js_error)?;
+    let result = js_call_async(
+        &js_get(&injected, "signer")?,
+        "signPayload",
+        &js_sys::Array::of1(&payload),
+    )
+    .await?;
+    let signature = js_get(&result, "signature")?
+        .as_string()
+        .ok_or_else(|| AppError::Signer("Extension returned no signature".to_string()))?;
+    from_hex(&signature)
+}
+
+/// Asks the extension to sign `message` as raw bytes. The extension wraps it
+/// in `<Bytes>...</Bytes>` itself and returns the raw signature.
+pub async fn extension_sign_raw(
+    message: &[u8],
+    account_source: &str,
+    account_address: &str,
+) -> Result<Vec<u8>, AppError> {
+    let payload = json!({
+        "address": account_address,
+        "data": to_hex(message),
+        "type": "bytes",
+    });
+
+    let injected = enable_extension(account_source).await?;
+    let payload = js_sys::JSON::parse(&payload.to_string()).map_err(js_error)?;
+    let result = js_call_async(
+        &js_get(&injected, "signer")?,
+        "signRaw",
+        &js_sys::Array::of1(&payload),
+    )
+    .await?;
+    let signature = js_get(&result, "signature")?
+        .as_string()
+        .ok_or_else(|| AppError::Signer("Extension returned no signature".to_string()))?;
+    from_hex(&signature)
+}
+
+fn to_hex(bytes: impl AsRef<[u8]>) -> String {
+    let hex: String = bytes
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    format!("0x{hex}")
+}
+
+fn encode_then_hex<E: Encode>(input: &E) -> String {
+    to_hex(input.encode())
+}
+
+fn from_hex(value: &str) -> Result<Vec<u8>, AppError> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|e| AppError::Decode(format!("Invalid hex string: {e}")))
+}
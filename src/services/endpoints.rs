@@ -0,0 +1,100 @@
+use crate::constants::constant::NODE_URL;
+use crate::services::error::AppError;
+use jsonrpsee_core::client::{Client, ClientT};
+use jsonrpsee_core::rpc_params;
+use jsonrpsee_wasm_client::WasmClientBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Endpoint {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct EndpointStore {
+    pub endpoints: Vec<Endpoint>,
+    pub active_url: String,
+}
+
+impl Default for EndpointStore {
+    fn default() -> Self {
+        Self {
+            endpoints: vec![Endpoint {
+                name: "Local node".to_string(),
+                url: NODE_URL.to_string(),
+            }],
+            active_url: NODE_URL.to_string(),
+        }
+    }
+}
+
+impl EndpointStore {
+    /// Endpoint urls in failover order: the active one first, then the ones
+    /// after it in the list, wrapping around.
+    pub fn failover_order(&self) -> Vec<String> {
+        let start = self
+            .endpoints
+            .iter()
+            .position(|e| e.url == self.active_url)
+            .unwrap_or(0);
+        let mut urls: Vec<String> = self
+            .endpoints
+            .iter()
+            .cycle()
+            .skip(start)
+            .take(self.endpoints.len())
+            .map(|e| e.url.clone())
+            .collect();
+        if urls.is_empty() {
+            urls.push(NODE_URL.to_string());
+        }
+        urls
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EndpointHealth {
+    pub latency_ms: f64,
+    pub best_block: u32,
+    pub genesis_hash: String,
+}
+
+#[derive(Deserialize)]
+struct Header {
+    number: String,
+}
+
+/// Opens a short-lived connection to `url` and reports its latency, best
+/// block and genesis hash.
+pub async fn check_endpoint(url: &str) -> Result<EndpointHealth, AppError> {
+    let client = WasmClientBuilder::default()
+        .build(url)
+        .await
+        .map_err(|e| AppError::Connection(e.to_string()))?;
+    endpoint_health(&client).await
+}
+
+/// Health of the node behind an already open `client`.
+pub async fn endpoint_health(client: &Client) -> Result<EndpointHealth, AppError> {
+    let started = js_sys::Date::now();
+    let header: Header = client
+        .request("chain_getHeader", rpc_params![])
+        .await
+        .map_err(|e| AppError::Connection(e.to_string()))?;
+    let latency_ms = js_sys::Date::now() - started;
+
+    let best_block = u32::from_str_radix(header.number.trim_start_matches("0x"), 16)
+        .map_err(|e| AppError::Decode(e.to_string()))?;
+
+    let genesis_hash: String = client
+        .request("chain_getBlockHash", rpc_params![0])
+        .await
+        .map_err(|e| AppError::Connection(e.to_string()))?;
+
+    Ok(EndpointHealth {
+        latency_ms,
+        best_block,
+        genesis_hash,
+    })
+}
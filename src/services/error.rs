@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt;
+use thiserror::Error;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ErrorString(pub String);
@@ -12,3 +13,50 @@ impl fmt::Display for ErrorString {
 }
 
 impl Error for ErrorString {}
+
+/// Errors returned by data loaders and surfaced through `AppErrorBoundary`.
+#[derive(Error, Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum AppError {
+    #[error("Cannot reach the node: {0}")]
+    Connection(String),
+    #[error("Failed to decode chain data: {0}")]
+    Decode(String),
+    #[error("RPC call failed: {0}")]
+    Rpc(String),
+    #[error("IPFS request failed: {0}")]
+    Ipfs(String),
+    #[error("Signing failed: {0}")]
+    Signer(String),
+    #[error("{0}")]
+    Validation(String),
+}
+
+impl From<ErrorString> for AppError {
+    fn from(e: ErrorString) -> Self {
+        AppError::Validation(e.0)
+    }
+}
+
+impl From<AppError> for ErrorString {
+    fn from(e: AppError) -> Self {
+        ErrorString(e.to_string())
+    }
+}
+
+impl From<subxt::Error> for AppError {
+    fn from(e: subxt::Error) -> Self {
+        match e {
+            subxt::Error::Io(_) => AppError::Connection(e.to_string()),
+            subxt::Error::Codec(_) | subxt::Error::Decode(_) | subxt::Error::Metadata(_) => {
+                AppError::Decode(e.to_string())
+            }
+            _ => AppError::Rpc(e.to_string()),
+        }
+    }
+}
+
+impl From<gloo::net::Error> for AppError {
+    fn from(e: gloo::net::Error) -> Self {
+        AppError::Ipfs(e.to_string())
+    }
+}
@@ -0,0 +1,2 @@
+pub use shivarthu_keys::encrypted_seed::{random_bytes, EncryptedSeed};
+pub use shivarthu_keys::error::KeystoreError;
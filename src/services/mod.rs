@@ -1,3 +1,18 @@
+pub mod account_backup;
+pub mod account_secret;
+pub mod chain_client;
+pub mod chain_head;
 pub mod common_imp;
 pub mod common_services;
+pub mod endpoints;
 pub mod error;
+pub mod keystore;
+pub mod polkadot_json;
+pub mod session;
+pub mod shivarthu_rpc;
+pub mod signed_message;
+pub mod signer;
+pub mod tauri_keystore;
+pub mod tx_lifecycle;
+pub mod tx_review;
+pub mod vote_vault;
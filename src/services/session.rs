@@ -0,0 +1,118 @@
+use crate::components::common::global_state::{GlobalState, GlobalStateStoreFields};
+use crate::services::signer::SignerKind;
+use crate::services::tauri_keystore;
+use codee::string::JsonSerdeCodec;
+use leptos::ev;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::storage::use_local_storage;
+use leptos_use::utils::Pausable;
+use leptos_use::{use_document_visibility, use_event_listener, use_interval_fn, use_window_focus};
+use reactive_stores::Store;
+use serde::{Deserialize, Serialize};
+use web_sys::VisibilityState;
+
+const IDLE_CHECK_MS: u64 = 10_000;
+
+/// When an unlocked session locks itself again.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SessionSettings {
+    /// Minutes without user activity before locking, `0` to never lock.
+    pub idle_timeout_minutes: u32,
+    pub lock_on_hidden: bool,
+    pub lock_on_blur: bool,
+}
+
+impl Default for SessionSettings {
+    fn default() -> Self {
+        Self {
+            idle_timeout_minutes: 15,
+            lock_on_hidden: false,
+            lock_on_blur: false,
+        }
+    }
+}
+
+/// Locks the signed-in account after inactivity or when the window is left,
+/// according to the persisted [`SessionSettings`].
+///
+/// Locking keeps the account address but zeroizes the seed phrase; signing
+/// again asks for the password, or for an extension account to be selected
+/// again.
+#[derive(Clone, Copy)]
+pub struct SessionManager {
+    state: Store<GlobalState>,
+    last_active_ms: RwSignal<f64>,
+}
+
+impl SessionManager {
+    /// Registers the activity listeners, so it has to be called from a
+    /// component, once.
+    pub fn new(state: Store<GlobalState>) -> Self {
+        let session = Self {
+            state,
+            last_active_ms: RwSignal::new(js_sys::Date::now()),
+        };
+        let (settings, _set_settings, _reset_settings) =
+            use_local_storage::<SessionSettings, JsonSerdeCodec>("session-settings");
+
+        let touch = move || session.last_active_ms.set(js_sys::Date::now());
+        let _ = use_event_listener(window(), ev::pointerdown, move |_| touch());
+        let _ = use_event_listener(window(), ev::keydown, move |_| touch());
+        let _ = use_event_listener(window(), ev::scroll, move |_| touch());
+
+        let Pausable { .. } = use_interval_fn(
+            move || {
+                let timeout_minutes = settings.with_untracked(|s| s.idle_timeout_minutes);
+                let idle_ms = js_sys::Date::now() - session.last_active_ms.get_untracked();
+                if timeout_minutes > 0 && idle_ms >= f64::from(timeout_minutes) * 60_000.0 {
+                    session.lock();
+                }
+            },
+            IDLE_CHECK_MS,
+        );
+
+        let visibility = use_document_visibility();
+        Effect::new(move |_| {
+            if visibility.get() == VisibilityState::Hidden
+                && settings.with_untracked(|s| s.lock_on_hidden)
+            {
+                session.lock();
+            }
+        });
+
+        let focused = use_window_focus();
+        Effect::new(move |_| {
+            if !focused.get() && settings.with_untracked(|s| s.lock_on_blur) {
+                session.lock();
+            }
+        });
+
+        session
+    }
+
+    pub fn is_unlocked(&self) -> Signal<bool> {
+        let phase_exists_in_state = self.state.phase_exists_in_state();
+        Signal::derive(move || phase_exists_in_state.get())
+    }
+
+    /// Zeroizes the seed phrase and, on desktop, locks the backend keystore.
+    /// Does nothing while locked, so the listeners can call it freely.
+    pub fn lock(&self) {
+        let phase_exists_in_state = self.state.phase_exists_in_state();
+        if !phase_exists_in_state.get_untracked() {
+            return;
+        }
+        // The phrase is `Zeroizing`, so dropping it wipes it.
+        *self.state.mnemonic_phrase().write() = None;
+        *phase_exists_in_state.write() = false;
+
+        if self.state.signer_kind().get_untracked() == SignerKind::TauriKeystore {
+            spawn_local(async {
+                if let Err(e) = tauri_keystore::lock(None).await {
+                    gloo::console::error!("Failed to lock keystore:", e.to_string());
+                }
+            });
+        }
+    }
+}
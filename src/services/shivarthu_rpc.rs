@@ -0,0 +1,166 @@
+use crate::services::error::AppError;
+use jsonrpsee_core::client::{Client, ClientT};
+use jsonrpsee_core::params::ArrayParams;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Identifies one Schelling game instance and, through it, which pallet's
+/// RPC namespace serves it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameKey {
+    ProfileValidation { profile_user_account: String },
+    PositiveExternality { user_to_calculate: String },
+    ProjectTips { project_id: u64 },
+    DepartmentFunding { department_required_fund_id: u64 },
+}
+
+impl GameKey {
+    pub fn rpc_prefix(&self) -> &'static str {
+        match self {
+            GameKey::ProfileValidation { .. } => "profilevalidation",
+            GameKey::PositiveExternality { .. } => "positiveexternality",
+            GameKey::ProjectTips { .. } => "projecttips",
+            GameKey::DepartmentFunding { .. } => "departmentfunding",
+        }
+    }
+
+    fn insert_param(&self, params: &mut ArrayParams) -> Result<(), serde_json::Error> {
+        match self {
+            GameKey::ProfileValidation {
+                profile_user_account,
+            } => params.insert(profile_user_account),
+            GameKey::PositiveExternality { user_to_calculate } => params.insert(user_to_calculate),
+            GameKey::ProjectTips { project_id } => params.insert(project_id),
+            GameKey::DepartmentFunding {
+                department_required_fund_id,
+            } => params.insert(department_required_fund_id),
+        }
+    }
+}
+
+/// Result of `*_drawingperiodend`, sent by the node as `(u64, u64, bool)`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "(u64, u64, bool)")]
+pub struct DrawingPeriodStatus {
+    pub draw_limit: u64,
+    pub draws_in_round: u64,
+    pub period_ended: bool,
+}
+
+impl From<(u64, u64, bool)> for DrawingPeriodStatus {
+    fn from((draw_limit, draws_in_round, period_ended): (u64, u64, bool)) -> Self {
+        Self {
+            draw_limit,
+            draws_in_round,
+            period_ended,
+        }
+    }
+}
+
+/// Typed wrapper over the custom RPC methods exposed by the Shivarthu node.
+#[derive(Clone)]
+pub struct ShivarthuRpc {
+    client: Arc<Client>,
+}
+
+impl ShivarthuRpc {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+
+    async fn request<R>(&self, method: &str, params: ArrayParams) -> Result<R, AppError>
+    where
+        R: serde::de::DeserializeOwned,
+    {
+        self.client
+            .request(method, params)
+            .await
+            .map_err(|e| AppError::Rpc(format!("{method}: {e}")))
+    }
+}
+
+fn params_error(e: serde_json::Error) -> AppError {
+    AppError::Validation(format!("Failed to encode RPC params: {e}"))
+}
+
+/// Declares every Shivarthu RPC method in one place.
+///
+/// Methods in the `game` block take a [`GameKey`] as their first argument and
+/// are sent as `<prefix>_<name>`; methods in the `global` block are sent with
+/// the name given verbatim.
+macro_rules! shivarthu_rpc {
+    (
+        game {
+            $(
+                $(#[$game_meta:meta])*
+                fn $game_method:ident($($game_arg:ident: $game_ty:ty),*) -> $game_ret:ty = $game_name:literal;
+            )*
+        }
+        global {
+        /// Whether `who` has staked as a juror in the positive externality game
+        /// of `user`. Other games have no such RPC; read their sortition tree
+        /// instead.
+        fn has_user_staked(user: String, who: String) -> bool = "positiveexternality_has_user_staked";
+        /// Amount `who` has staked as a juror in the positive externality game
+        /// of `user`.
+        fn user_staked_value(user: String, who: String) -> u64 = "positiveexternality_user_staked_value";
+            $(
+                $(#[$meta:meta])*
+                fn $method:ident($($arg:ident: $ty:ty),*) -> $ret:ty = $name:literal;
+            )*
+        }
+    ) => {
+        impl ShivarthuRpc {
+            $(
+                $(#[$game_meta])*
+                pub async fn $game_method(
+                    &self,
+                    key: &GameKey,
+                    $($game_arg: $game_ty),*
+                ) -> Result<$game_ret, AppError> {
+                    let mut params = ArrayParams::new();
+                    key.insert_param(&mut params).map_err(params_error)?;
+                    $(params.insert($game_arg).map_err(params_error)?;)*
+                    let method = format!("{}_{}", key.rpc_prefix(), $game_name);
+                    self.request(&method, params).await
+                }
+            )*
+
+            $(
+                $(#[$meta])*
+                pub async fn $method(&self, $($arg: $ty),*) -> Result<$ret, AppError> {
+                    #[allow(unused_mut)]
+                    let mut params = ArrayParams::new();
+                    $(params.insert($arg).map_err(params_error)?;)*
+                    self.request($name, params).await
+                }
+            )*
+        }
+    };
+}
+
+shivarthu_rpc! {
+    game {
+        /// Block at which the evidence period ends.
+        fn evidence_end_block() -> Option<u32> = "evidenceperiodendblock";
+        /// Block at which the staking period ends.
+        fn staking_end_block() -> Option<u32> = "stakingperiodendblock";
+        /// Progress of juror drawing.
+        fn drawing_period_end() -> DrawingPeriodStatus = "drawingperiodend";
+        /// Block at which the commit period ends.
+        fn commit_end_block() -> Option<u32> = "commitendblock";
+        /// Block at which the vote (reveal) period ends.
+        fn vote_end_block() -> Option<u32> = "voteendblock";
+        /// Whether `who` was drawn as a juror.
+        fn selected_juror(who: String) -> bool = "selectedjuror";
+    }
+    global {
+        fn post_by_address_length(user: String) -> u64 = "positiveexternality_postbyaddresslength";
+        fn paginate_posts(user: String, page: u64, page_size: u64) -> Option<Vec<u64>> = "positiveexternality_paginateposts";
+        fn paginate_posts_latest(user: String, page: u64, page_size: u64) -> Option<Vec<u64>> = "positiveexternality_paginateposts_latest";
+        fn all_posts_length() -> u64 = "all_postlength";
+        fn paginate_all_posts(page: u64, page_size: u64) -> Option<Vec<u64>> = "positiveexternality_paginateall_posts";
+        fn validation_list_length() -> u64 = "positiveexternality_validationlistlength";
+        fn validation_list_latest(page: u64, page_size: u64) -> Option<Vec<String>> = "positiveexternality_validationlist_latest";
+    }
+}
@@ -0,0 +1,128 @@
+use crate::services::account_secret::{keypair_from_secret, CryptoType, SIGNING_CONTEXT};
+use crate::services::common_services::{account_id_from_str, extension_sign_raw};
+use crate::services::error::AppError;
+use crate::services::signer::SignerKind;
+use crate::services::tauri_keystore;
+use schnorrkel::{signing_context, PublicKey, Signature};
+use serde::{Deserialize, Serialize};
+use subxt::utils::MultiSignature;
+
+const BYTES_PREFIX: &str = "<Bytes>";
+const BYTES_SUFFIX: &str = "</Bytes>";
+
+/// Wraps `message` the way polkadot.js does before signing raw bytes, so a
+/// signed message can never pass for a transaction payload.
+pub fn wrap_bytes(message: &str) -> String {
+    if message.starts_with(BYTES_PREFIX) && message.ends_with(BYTES_SUFFIX) {
+        message.to_string()
+    } else {
+        format!("{BYTES_PREFIX}{message}{BYTES_SUFFIX}")
+    }
+}
+
+/// Message signed by an address, in the JSON layout shared by the sign and
+/// verify pages.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SignedMessage {
+    pub address: String,
+    pub message: String,
+    /// `0x` prefixed hex of the 64 byte sr25519 signature.
+    pub signature: String,
+}
+
+impl SignedMessage {
+    pub fn parse(json: &str) -> Result<Self, AppError> {
+        serde_json::from_str(json)
+            .map_err(|e| AppError::Validation(format!("Not a signed message: {e}")))
+    }
+
+    pub fn to_json(&self) -> Result<String, AppError> {
+        serde_json::to_string_pretty(self).map_err(|e| AppError::Validation(e.to_string()))
+    }
+
+    /// Checks the sr25519 signature over the wrapped message. A signature of
+    /// the bare message is rejected, since those bytes could be a transaction
+    /// payload.
+    pub fn verify(&self) -> Result<bool, AppError> {
+        let account_id = account_id_from_str(self.address.trim())?;
+        let public = PublicKey::from_bytes(&account_id.0)
+            .map_err(|e| AppError::Validation(format!("Invalid public key: {e}")))?;
+        let signature = self.signature.trim();
+        let bytes = hex::decode(signature.strip_prefix("0x").unwrap_or(signature))
+            .map_err(|e| AppError::Validation(format!("Invalid signature hex: {e}")))?;
+        let signature = Signature::from_bytes(&bytes)
+            .map_err(|e| AppError::Validation(format!("Invalid signature: {e}")))?;
+        Ok(public
+            .verify_simple(
+                SIGNING_CONTEXT,
+                wrap_bytes(&self.message).as_bytes(),
+                &signature,
+            )
+            .is_ok())
+    }
+}
+
+/// Signs `message` with the signed-in account. Only sr25519 keys sign
+/// messages and watch-only accounts are refused.
+pub async fn sign_message(
+    signer_kind: &SignerKind,
+    account_address: &str,
+    secret: Option<&str>,
+    crypto_type: CryptoType,
+    unlocked: bool,
+    message: &str,
+) -> Result<SignedMessage, AppError> {
+    if account_address.is_empty() {
+        return Err(AppError::Signer("Sign in to sign a message".to_string()));
+    }
+    let keystore = matches!(
+        signer_kind,
+        SignerKind::LocalKeystore | SignerKind::TauriKeystore
+    );
+    if keystore && crypto_type != CryptoType::Sr25519 {
+        return Err(AppError::Signer(format!(
+            "{} accounts cannot sign messages, use an sr25519 account",
+            crypto_type.label()
+        )));
+    }
+    let signature = match signer_kind {
+        SignerKind::LocalKeystore => {
+            let secret = secret.ok_or_else(|| AppError::Signer("Account is locked".to_string()))?;
+            let keypair =
+                keypair_from_secret(secret).map_err(|e| AppError::Signer(e.to_string()))?;
+            keypair
+                .sign(signing_context(SIGNING_CONTEXT).bytes(wrap_bytes(message).as_bytes()))
+                .to_bytes()
+                .to_vec()
+        }
+        SignerKind::TauriKeystore => {
+            if !unlocked {
+                return Err(AppError::Signer("Account is locked".to_string()));
+            }
+            match tauri_keystore::sign_message(account_address, message).await? {
+                MultiSignature::Sr25519(signature) => signature.to_vec(),
+                _ => {
+                    return Err(AppError::Signer(
+                        "Only sr25519 accounts can sign messages".to_string(),
+                    ))
+                }
+            }
+        }
+        SignerKind::InjectedExtension { source } => {
+            if !unlocked {
+                return Err(AppError::Signer("Account is locked".to_string()));
+            }
+            extension_sign_raw(message.as_bytes(), source, account_address).await?
+        }
+        SignerKind::WatchOnly => {
+            return Err(AppError::Signer(format!(
+                "{account_address} is a watch-only account and cannot sign"
+            )))
+        }
+    };
+    Ok(SignedMessage {
+        address: account_address.to_string(),
+        message: message.to_string(),
+        signature: format!("0x{}", hex::encode(signature)),
+    })
+}
@@ -0,0 +1,255 @@
+use crate::services::account_secret::{AccountKeypair, CryptoType};
+use crate::services::common_services::{account_id_from_str, extension_signature_for_extrinsic};
+use crate::services::error::AppError;
+use crate::services::tauri_keystore;
+use serde::{Deserialize, Serialize};
+use subxt::config::DefaultExtrinsicParamsBuilder;
+use subxt::ext::codec::Decode;
+use subxt::tx::{Payload, SubmittableTransaction};
+use subxt::utils::{AccountId32, MultiSignature};
+use subxt::{OnlineClient, PolkadotConfig};
+
+pub type SignedTransaction = SubmittableTransaction<PolkadotConfig, OnlineClient<PolkadotConfig>>;
+
+/// How an account signs, stored with the account so each one keeps its
+/// own backend.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub enum SignerKind {
+    /// Seed encrypted in the local keystore, unlocked with the password.
+    #[default]
+    LocalKeystore,
+    /// Browser wallet extension registered under `source` in
+    /// `window.injectedWeb3`.
+    InjectedExtension { source: String },
+    /// Keystore held by the Tauri backend of the desktop build.
+    TauriKeystore,
+    /// Address only, to follow an account's balance, stakes and games.
+    /// Never signs.
+    WatchOnly,
+}
+
+/// Turns a call into a signed extrinsic ready to submit.
+///
+/// Futures are not `Send` on wasm, so the `async fn` here is fine.
+#[allow(async_fn_in_trait)]
+pub trait Signer {
+    fn account_id(&self) -> AccountId32;
+
+    async fn sign<Call: Payload>(
+        &self,
+        api: &OnlineClient<PolkadotConfig>,
+        call: &Call,
+    ) -> Result<SignedTransaction, AppError>;
+}
+
+/// Signs with the keypair of the unlocked account secret, a seed phrase or
+/// the raw key of an imported JSON keystore.
+///
+/// Built for each transaction and dropped right after; schnorrkel and
+/// ed25519-dalek zeroize the secret key on drop.
+pub struct LocalKeystoreSigner {
+    keypair: AccountKeypair,
+}
+
+impl LocalKeystoreSigner {
+    pub fn from_secret(crypto_type: CryptoType, secret: &str) -> Result<Self, AppError> {
+        let keypair = AccountKeypair::from_secret(crypto_type, secret)
+            .map_err(|e| AppError::Signer(e.to_string()))?;
+        Ok(Self { keypair })
+    }
+}
+
+impl Signer for LocalKeystoreSigner {
+    fn account_id(&self) -> AccountId32 {
+        self.keypair.account_id()
+    }
+
+    async fn sign<Call: Payload>(
+        &self,
+        api: &OnlineClient<PolkadotConfig>,
+        call: &Call,
+    ) -> Result<SignedTransaction, AppError> {
+        let account_id = self.account_id();
+        let mut partial_signed = api
+            .tx()
+            .create_partial(call, &account_id, Default::default())
+            .await?;
+        let signature = self.keypair.sign(&partial_signed.signer_payload());
+        Ok(partial_signed.sign_with_account_and_signature(&account_id, &signature))
+    }
+}
+
+/// Signs through a polkadot.js compatible browser extension.
+///
+/// The extension only returns a signature, so the extrinsic is built
+/// offline as immortal with the same nonce that was sent for signing.
+pub struct InjectedExtensionSigner {
+    account_id: AccountId32,
+    address: String,
+    source: String,
+}
+
+impl InjectedExtensionSigner {
+    pub fn new(address: &str, source: &str) -> Result<Self, AppError> {
+        Ok(Self {
+            account_id: account_id_from_str(address)?,
+            address: address.to_string(),
+            source: source.to_string(),
+        })
+    }
+}
+
+impl Signer for InjectedExtensionSigner {
+    fn account_id(&self) -> AccountId32 {
+        self.account_id.clone()
+    }
+
+    async fn sign<Call: Payload>(
+        &self,
+        api: &OnlineClient<PolkadotConfig>,
+        call: &Call,
+    ) -> Result<SignedTransaction, AppError> {
+        let account_nonce = api.tx().account_nonce(&self.account_id).await?;
+        let call_data = api.tx().call_data(call)?;
+
+        let signature = extension_signature_for_extrinsic(
+            &call_data,
+            api,
+            account_nonce,
+            &self.source,
+            &self.address,
+        )
+        .await?;
+        let multi_signature = MultiSignature::decode(&mut &signature[..])
+            .map_err(|e| AppError::Signer(format!("Invalid extension signature: {e}")))?;
+
+        let params = DefaultExtrinsicParamsBuilder::<PolkadotConfig>::new()
+            .nonce(account_nonce)
+            .immortal()
+            .build();
+        let mut partial_signed = api.tx().create_partial_offline(call, params)?;
+        Ok(partial_signed.sign_with_account_and_signature(&self.account_id, &multi_signature))
+    }
+}
+
+/// Signs with an account unlocked in the Tauri backend, which only hands
+/// back the signature.
+pub struct TauriKeystoreSigner {
+    account_id: AccountId32,
+    address: String,
+}
+
+impl TauriKeystoreSigner {
+    pub fn new(address: &str) -> Result<Self, AppError> {
+        Ok(Self {
+            account_id: account_id_from_str(address)?,
+            address: address.to_string(),
+        })
+    }
+}
+
+impl Signer for TauriKeystoreSigner {
+    fn account_id(&self) -> AccountId32 {
+        self.account_id.clone()
+    }
+
+    async fn sign<Call: Payload>(
+        &self,
+        api: &OnlineClient<PolkadotConfig>,
+        call: &Call,
+    ) -> Result<SignedTransaction, AppError> {
+        let mut partial_signed = api
+            .tx()
+            .create_partial(call, &self.account_id, Default::default())
+            .await?;
+        let signature =
+            tauri_keystore::sign_payload(&self.address, &partial_signed.signer_payload()).await?;
+        Ok(partial_signed.sign_with_account_and_signature(&self.account_id, &signature))
+    }
+}
+
+/// `call` from `account_id` with an all-zero signature of the account's key
+/// type, so the call can be reviewed and its fee estimated before the user
+/// agrees to sign. The node rejects it if it is ever submitted.
+pub async fn preview_transaction<Call: Payload>(
+    api: &OnlineClient<PolkadotConfig>,
+    call: &Call,
+    account_id: &AccountId32,
+    crypto_type: CryptoType,
+) -> Result<SignedTransaction, AppError> {
+    let mut partial_signed = api
+        .tx()
+        .create_partial(call, account_id, Default::default())
+        .await?;
+    let signature = match crypto_type {
+        CryptoType::Sr25519 => MultiSignature::Sr25519([0; 64]),
+        CryptoType::Ed25519 => MultiSignature::Ed25519([0; 64]),
+        CryptoType::Ecdsa => MultiSignature::Ecdsa([0; 65]),
+    };
+    Ok(partial_signed.sign_with_account_and_signature(account_id, &signature))
+}
+
+/// Signer of the signed-in account, picked from its [`SignerKind`].
+pub enum AccountSigner {
+    Local(LocalKeystoreSigner),
+    Injected(InjectedExtensionSigner),
+    Tauri(TauriKeystoreSigner),
+}
+
+impl AccountSigner {
+    /// `None` while no account is signed in, the session is locked or the
+    /// account is watch-only.
+    pub fn for_account(
+        signer_kind: &SignerKind,
+        account_address: &str,
+        mnemonic_phrase: Option<&str>,
+        crypto_type: CryptoType,
+        unlocked: bool,
+    ) -> Option<Result<Self, AppError>> {
+        match signer_kind {
+            SignerKind::LocalKeystore => mnemonic_phrase.map(|secret| {
+                LocalKeystoreSigner::from_secret(crypto_type, secret).map(AccountSigner::Local)
+            }),
+            SignerKind::InjectedExtension { source } => {
+                if account_address.is_empty() || !unlocked {
+                    None
+                } else {
+                    Some(
+                        InjectedExtensionSigner::new(account_address, source)
+                            .map(AccountSigner::Injected),
+                    )
+                }
+            }
+            SignerKind::TauriKeystore => {
+                if account_address.is_empty() || !unlocked {
+                    None
+                } else {
+                    Some(TauriKeystoreSigner::new(account_address).map(AccountSigner::Tauri))
+                }
+            }
+            SignerKind::WatchOnly => None,
+        }
+    }
+}
+
+impl Signer for AccountSigner {
+    fn account_id(&self) -> AccountId32 {
+        match self {
+            AccountSigner::Local(signer) => signer.account_id(),
+            AccountSigner::Injected(signer) => signer.account_id(),
+            AccountSigner::Tauri(signer) => signer.account_id(),
+        }
+    }
+
+    async fn sign<Call: Payload>(
+        &self,
+        api: &OnlineClient<PolkadotConfig>,
+        call: &Call,
+    ) -> Result<SignedTransaction, AppError> {
+        match self {
+            AccountSigner::Local(signer) => signer.sign(api, call).await,
+            AccountSigner::Injected(signer) => signer.sign(api, call).await,
+            AccountSigner::Tauri(signer) => signer.sign(api, call).await,
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::services::account_secret::CryptoType;
+use crate::services::error::AppError;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use subxt::ext::codec::Decode;
+use subxt::utils::MultiSignature;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct KeystoreAccount {
+    pub address: String,
+    pub name: String,
+    #[serde(default)]
+    pub crypto_type: CryptoType,
+    pub unlocked: bool,
+}
+
+#[derive(Serialize)]
+struct NoArgs {}
+
+#[derive(Serialize)]
+struct AddressArgs<'a> {
+    address: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportArgs<'a> {
+    name: &'a str,
+    crypto_type: CryptoType,
+    phrase: &'a str,
+    password: &'a str,
+}
+
+#[derive(Serialize)]
+struct CreateSeedArgs {
+    words: usize,
+}
+
+#[derive(Serialize)]
+struct UnlockArgs<'a> {
+    address: &'a str,
+    password: &'a str,
+}
+
+#[derive(Serialize)]
+struct SignPayloadArgs<'a> {
+    address: &'a str,
+    payload: &'a [u8],
+}
+
+#[derive(Serialize)]
+struct SignMessageArgs<'a> {
+    address: &'a str,
+    message: &'a str,
+}
+
+/// True inside the desktop app, where `window.__TAURI__` is injected and the
+/// backend owns the keystore. The webview only gets addresses and signatures.
+pub fn is_available() -> bool {
+    js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("__TAURI__"))
+        .map(|tauri| !tauri.is_undefined())
+        .unwrap_or(false)
+}
+
+async fn call<A: Serialize, R: DeserializeOwned>(cmd: &str, args: &A) -> Result<R, AppError> {
+    let args = serde_wasm_bindgen::to_value(args)
+        .map_err(|e| AppError::Signer(format!("Cannot encode {cmd} arguments: {e}")))?;
+    let result = invoke(cmd, args)
+        .await
+        .map_err(|e| AppError::Signer(e.as_string().unwrap_or_else(|| format!("{cmd} failed"))))?;
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| AppError::Decode(format!("Unexpected {cmd} response: {e}")))
+}
+
+/// New seed phrase of `words` words, generated by the backend. It is shown
+/// for backup and then imported with [`import_account`].
+pub async fn create_seed(words: usize) -> Result<Zeroizing<String>, AppError> {
+    call::<_, String>("create_seed", &CreateSeedArgs { words })
+        .await
+        .map(Zeroizing::new)
+}
+
+pub async fn list_accounts() -> Result<Vec<KeystoreAccount>, AppError> {
+    call("keystore_list", &NoArgs {}).await
+}
+
+/// `phrase` is a secret URI, so it may carry a derivation path and a BIP39
+/// password.
+pub async fn import_account(
+    name: &str,
+    crypto_type: CryptoType,
+    phrase: &str,
+    password: &str,
+) -> Result<KeystoreAccount, AppError> {
+    call(
+        "keystore_import",
+        &ImportArgs {
+            name,
+            crypto_type,
+            phrase,
+            password,
+        },
+    )
+    .await
+}
+
+pub async fn unlock(address: &str, password: &str) -> Result<(), AppError> {
+    call("keystore_unlock", &UnlockArgs { address, password }).await
+}
+
+/// Locks `address`, or every account when `None`.
+pub async fn lock(address: Option<&str>) -> Result<(), AppError> {
+    call("keystore_lock", &AddressArgs { address }).await
+}
+
+fn decode_signature(signature: Vec<u8>) -> Result<MultiSignature, AppError> {
+    MultiSignature::decode(&mut &signature[..])
+        .map_err(|e| AppError::Signer(format!("Keystore returned an invalid signature: {e}")))
+}
+
+/// Signature of a transaction's signer payload by an unlocked account, of
+/// the account's key type.
+pub async fn sign_payload(address: &str, payload: &[u8]) -> Result<MultiSignature, AppError> {
+    decode_signature(
+        call(
+            "keystore_sign_payload",
+            &SignPayloadArgs { address, payload },
+        )
+        .await?,
+    )
+}
+
+/// Signature of `message`, which the backend wraps in `<Bytes>…</Bytes>`
+/// before signing.
+pub async fn sign_message(address: &str, message: &str) -> Result<MultiSignature, AppError> {
+    decode_signature(
+        call(
+            "keystore_sign_message",
+            &SignMessageArgs { address, message },
+        )
+        .await?,
+    )
+}
@@ -0,0 +1,196 @@
+use subxt::error::DispatchError;
+use subxt::tx::{TxProgress, TxStatus};
+use subxt::{OnlineClient, PolkadotConfig};
+
+/// Where a submitted extrinsic is, from signing until it is finalized or
+/// rejected by the pool.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TxStage {
+    Signing,
+    Validated,
+    Broadcast,
+    InBestBlock {
+        block_hash: String,
+    },
+    /// Retracted by a reorg; the pool may still include it again.
+    NoLongerInBestBlock,
+    Finalized {
+        block_hash: String,
+        extrinsic_hash: String,
+        outcome: TxOutcome,
+    },
+    Dropped(String),
+    Invalid(String),
+    /// Replaced by another extrinsic with the same nonce.
+    Usurped(String),
+    Error(String),
+    /// The user declined to submit after reviewing it.
+    Cancelled,
+}
+
+impl TxStage {
+    /// No further status updates follow a final stage.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            TxStage::Finalized { .. }
+                | TxStage::Dropped(_)
+                | TxStage::Invalid(_)
+                | TxStage::Usurped(_)
+                | TxStage::Error(_)
+                | TxStage::Cancelled
+        )
+    }
+
+    /// Position in the happy path, used to draw the progress steps.
+    pub fn step(&self) -> usize {
+        match self {
+            TxStage::Signing => 0,
+            TxStage::Validated => 1,
+            TxStage::Broadcast | TxStage::NoLongerInBestBlock => 2,
+            TxStage::InBestBlock { .. } => 3,
+            TxStage::Finalized { .. } => 4,
+            TxStage::Dropped(_)
+            | TxStage::Invalid(_)
+            | TxStage::Usurped(_)
+            | TxStage::Error(_)
+            | TxStage::Cancelled => 0,
+        }
+    }
+}
+
+/// Result of the extrinsic once its block is finalized.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TxOutcome {
+    Success,
+    Failed(DispatchFailure),
+}
+
+/// Decoded dispatch error of a failed extrinsic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DispatchFailure {
+    pub pallet: Option<String>,
+    pub variant: Option<String>,
+    pub message: String,
+}
+
+impl From<subxt::Error> for DispatchFailure {
+    fn from(e: subxt::Error) -> Self {
+        match e {
+            subxt::Error::Runtime(DispatchError::Module(module_error)) => {
+                match module_error.details() {
+                    Ok(details) => {
+                        let pallet = details.pallet.name().to_string();
+                        let variant = details.variant.name.clone();
+                        let docs = details.variant.docs.join(" ");
+                        let message = error_message(&variant)
+                            .map(str::to_string)
+                            .or_else(|| (!docs.trim().is_empty()).then(|| docs.trim().to_string()))
+                            .unwrap_or_else(|| format!("{pallet}::{variant}"));
+                        DispatchFailure {
+                            pallet: Some(pallet),
+                            variant: Some(variant),
+                            message,
+                        }
+                    }
+                    Err(_) => DispatchFailure {
+                        pallet: None,
+                        variant: None,
+                        message: module_error.to_string(),
+                    },
+                }
+            }
+            e => DispatchFailure {
+                pallet: None,
+                variant: None,
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+/// Messages for the pallet errors users run into most.
+fn error_message(variant: &str) -> Option<&'static str> {
+    let message = match variant {
+        "PeriodDontMatch" => "This action is not allowed in the current period of the game",
+        "PeriodExists" => "The game period has already started",
+        "PeriodDoesNotExists" => "The game has not started yet",
+        "EvidencePeriodNotOver" => "The evidence period is not over yet",
+        "StakingPeriodNotOver" => "The staking period is not over yet",
+        "CommitPeriodNotOver" => "The commit period is not over yet",
+        "VotePeriodNotOver" => "The vote period is not over yet",
+        "TimeForStakingOver" => "The time for staking is over",
+        "TimeForStakingNotOver" => "The time for staking is not over yet",
+        "AlreadyStaked" => "You have already applied as a juror for this game",
+        "JurorStakeLessThanMin" | "LessThanMinStake" => "The stake is less than the minimum stake",
+        "CannotStakeNow" => "Staking is not possible for this game right now",
+        "SelectedAsJuror" => "You are selected as a juror and cannot unstake",
+        "MaxDrawExceeded" => "All jurors have already been drawn",
+        "MaxJurorNotDrawn" => "Not all jurors have been drawn yet",
+        "AlreadyUnstaked" => "You have already unstaked",
+        "StakeDoesNotExists" | "JurorDoesNotExists" => "You are not a juror in this game",
+        "VoteStatusNotCommited" => "You have not committed a vote",
+        "CommitDoesNotExists" => "No committed vote was found for you",
+        "CommitDoesNotMatch" => "The vote and salt do not match your committed vote",
+        "NotValidChoice" | "ChoiceOutOfRange" => "The choice is not valid for this game",
+        "VoteNotRevealed" => "You have not revealed your vote",
+        "AlreadyGotIncentives" => "You have already claimed your incentives",
+        "AlreadyIncentivesAdded" => "Incentives were already added for this game",
+        "NoIncentiveCount" => "There are no incentives to claim",
+        "CitizenNotApproved" => "Your profile has not been validated yet",
+        "CitizenDoNotExists" => "No profile exists for this account",
+        "ProfileIsAlreadyValidated" => "The profile is already validated",
+        "NotAPostOwner" | "NotProfileUser" => "Only the owner can do this",
+        "ValidationPositiveExternalityIsOff" => "The user has not turned on validation",
+        "InsufficientBalance" => "The account balance is too low",
+        _ => return None,
+    };
+    Some(message)
+}
+
+/// Follows `progress` until it reaches a final stage, reporting each stage
+/// through `set_stage`.
+pub async fn follow_transaction(
+    mut progress: TxProgress<PolkadotConfig, OnlineClient<PolkadotConfig>>,
+    set_stage: impl Fn(TxStage),
+) {
+    while let Some(status) = progress.next().await {
+        let stage = match status {
+            Ok(TxStatus::Validated) => TxStage::Validated,
+            Ok(TxStatus::Broadcasted) => TxStage::Broadcast,
+            Ok(TxStatus::NoLongerInBestBlock) => TxStage::NoLongerInBestBlock,
+            Ok(TxStatus::InBestBlock(in_block)) => TxStage::InBestBlock {
+                block_hash: format!("{:?}", in_block.block_hash()),
+            },
+            Ok(TxStatus::InFinalizedBlock(in_block)) => {
+                let block_hash = format!("{:?}", in_block.block_hash());
+                let extrinsic_hash = format!("{:?}", in_block.extrinsic_hash());
+                let outcome = match in_block.wait_for_success().await {
+                    Ok(_) => TxOutcome::Success,
+                    Err(e) => TxOutcome::Failed(e.into()),
+                };
+                TxStage::Finalized {
+                    block_hash,
+                    extrinsic_hash,
+                    outcome,
+                }
+            }
+            Ok(TxStatus::Dropped { message }) => TxStage::Dropped(message),
+            // The pool reports usurped extrinsics as invalid.
+            Ok(TxStatus::Invalid { message }) if message.to_lowercase().contains("usurped") => {
+                TxStage::Usurped(message)
+            }
+            Ok(TxStatus::Invalid { message }) => TxStage::Invalid(message),
+            Ok(TxStatus::Error { message }) => TxStage::Error(message),
+            Err(e) => TxStage::Error(format!("Lost track of transaction: {e}")),
+        };
+        let is_final = stage.is_final();
+        set_stage(stage);
+        if is_final {
+            return;
+        }
+    }
+    set_stage(TxStage::Error(
+        "The node stopped reporting the transaction status".to_string(),
+    ));
+}
@@ -0,0 +1,231 @@
+use crate::services::common_services::polkadot;
+use crate::services::error::AppError;
+use crate::services::signer::SignedTransaction;
+use crate::services::tx_lifecycle::DispatchFailure;
+use subxt::error::DispatchError;
+use subxt::ext::codec::{Decode, Error as CodecError, Input};
+use subxt::ext::scale_value::{self, Composite, ValueDef};
+use subxt::utils::AccountId32;
+use subxt::{OnlineClient, PolkadotConfig};
+
+/// Pallet, call and arguments decoded from the call data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallSummary {
+    pub pallet: String,
+    pub call: String,
+    pub args: Vec<(String, String)>,
+}
+
+/// What applying the extrinsic on top of the latest block would do.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DryRunOutcome {
+    Success,
+    DispatchFailed(DispatchFailure),
+    /// Rejected before dispatch, so no fee would be charged either.
+    Invalid(String),
+}
+
+/// Everything shown to the user before the extrinsic is signed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TxReview {
+    pub call: CallSummary,
+    pub partial_fee: u128,
+    pub free_balance: u128,
+}
+
+impl TxReview {
+    pub fn balance_after_fee(&self) -> u128 {
+        self.free_balance.saturating_sub(self.partial_fee)
+    }
+}
+
+/// Decodes the call and estimates the fee of `preview`, an extrinsic built by
+/// [`preview_transaction`](crate::services::signer::preview_transaction).
+pub async fn review_transaction(
+    api: &OnlineClient<PolkadotConfig>,
+    preview: &SignedTransaction,
+    call_data: &[u8],
+    account_id: AccountId32,
+) -> Result<TxReview, AppError> {
+    let call = decode_call(api, call_data)?;
+    let partial_fee = preview.partial_fee_estimate().await?;
+
+    let account_storage = polkadot::storage().system().account(account_id);
+    let free_balance = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&account_storage)
+        .await?
+        .map(|account| account.data.free)
+        .unwrap_or_default();
+
+    Ok(TxReview {
+        call,
+        partial_fee,
+        free_balance,
+    })
+}
+
+fn decode_call(
+    api: &OnlineClient<PolkadotConfig>,
+    call_data: &[u8],
+) -> Result<CallSummary, AppError> {
+    let metadata = api.metadata();
+    let call_ty = metadata.outer_enums().call_enum_ty();
+    let value = scale_value::scale::decode_as_type(&mut &call_data[..], call_ty, metadata.types())
+        .map_err(|e| AppError::Decode(format!("Cannot decode call: {e}")))?;
+
+    let not_a_call = || AppError::Decode("Call data is not a runtime call".to_string());
+    let ValueDef::Variant(pallet) = value.value else {
+        return Err(not_a_call());
+    };
+    let call = pallet.values.into_values().next().ok_or_else(not_a_call)?;
+    let ValueDef::Variant(call) = call.value else {
+        return Err(not_a_call());
+    };
+    let args = match call.values {
+        Composite::Named(fields) => fields
+            .into_iter()
+            .map(|(name, value)| (name, value.to_string()))
+            .collect(),
+        Composite::Unnamed(values) => values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| (index.to_string(), value.to_string()))
+            .collect(),
+    };
+
+    Ok(CallSummary {
+        pallet: pallet.name,
+        call: call.name,
+        args,
+    })
+}
+
+/// `ApplyExtrinsicResult` with the dispatch error kept as raw bytes so it can
+/// be decoded against the metadata.
+#[derive(Debug, PartialEq)]
+enum ApplyExtrinsicResult {
+    Applied,
+    DispatchFailed(Vec<u8>),
+    Invalid(&'static str),
+}
+
+impl Decode for ApplyExtrinsicResult {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+        match input.read_byte()? {
+            0 => match input.read_byte()? {
+                0 => Ok(ApplyExtrinsicResult::Applied),
+                1 => {
+                    let mut dispatch_error = Vec::new();
+                    while let Ok(byte) = input.read_byte() {
+                        dispatch_error.push(byte);
+                    }
+                    Ok(ApplyExtrinsicResult::DispatchFailed(dispatch_error))
+                }
+                _ => Err("Invalid DispatchOutcome".into()),
+            },
+            1 => {
+                let kind = input.read_byte()?;
+                let reason = input.read_byte()?;
+                // Custom validity errors carry one more byte.
+                let _ = input.read_byte();
+                Ok(ApplyExtrinsicResult::Invalid(validity_error(kind, reason)))
+            }
+            _ => Err("Invalid ApplyExtrinsicResult".into()),
+        }
+    }
+}
+
+/// Names the `TransactionValidityError` variant.
+fn validity_error(kind: u8, reason: u8) -> &'static str {
+    match (kind, reason) {
+        (0, 0) => "The call of the transaction is not expected",
+        (0, 1) => "The account cannot pay the transaction fee",
+        (0, 2) => "The transaction nonce is ahead of the account nonce",
+        (0, 3) => "The transaction nonce was already used",
+        (0, 4) => "The transaction signature is invalid",
+        (0, 5) => "The transaction birth block is ancient",
+        (0, 6) => "The transaction would exhaust the block resources",
+        (0, 7) => "The transaction was rejected by the runtime",
+        (0, 10) => "The transaction signer is invalid",
+        (0, _) => "The transaction is invalid",
+        (1, 0) => "The account could not be looked up",
+        _ => "The transaction validity could not be determined",
+    }
+}
+
+/// Applies the extrinsic with `BlockBuilder_apply_extrinsic` against the
+/// state of the latest block. The result is discarded by the node.
+pub async fn dry_run(
+    api: &OnlineClient<PolkadotConfig>,
+    signed: &SignedTransaction,
+) -> Result<DryRunOutcome, AppError> {
+    let result: ApplyExtrinsicResult = api
+        .runtime_api()
+        .at_latest()
+        .await?
+        .call_raw("BlockBuilder_apply_extrinsic", Some(signed.encoded()))
+        .await?;
+
+    Ok(match result {
+        ApplyExtrinsicResult::Applied => DryRunOutcome::Success,
+        ApplyExtrinsicResult::DispatchFailed(bytes) => {
+            let failure = match DispatchError::decode_from(bytes, api.metadata()) {
+                Ok(dispatch_error) => subxt::Error::Runtime(dispatch_error).into(),
+                Err(e) => e.into(),
+            };
+            DryRunOutcome::DispatchFailed(failure)
+        }
+        ApplyExtrinsicResult::Invalid(reason) => DryRunOutcome::Invalid(reason.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(bytes: &[u8]) -> Result<ApplyExtrinsicResult, CodecError> {
+        ApplyExtrinsicResult::decode(&mut &bytes[..])
+    }
+
+    #[test]
+    fn decodes_applied() {
+        assert_eq!(decode(&[0, 0]).unwrap(), ApplyExtrinsicResult::Applied);
+    }
+
+    #[test]
+    fn keeps_the_dispatch_error_bytes() {
+        // `DispatchError::Module` of pallet 40, error 3.
+        assert_eq!(
+            decode(&[0, 1, 3, 40, 3, 0, 0, 0]).unwrap(),
+            ApplyExtrinsicResult::DispatchFailed(vec![3, 40, 3, 0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn names_the_validity_error() {
+        assert_eq!(
+            decode(&[1, 0, 4]).unwrap(),
+            ApplyExtrinsicResult::Invalid("The transaction signature is invalid")
+        );
+        assert_eq!(
+            decode(&[1, 1, 0]).unwrap(),
+            ApplyExtrinsicResult::Invalid("The account could not be looked up")
+        );
+        // `InvalidTransaction::Custom(42)` carries the pallet's code.
+        assert_eq!(
+            decode(&[1, 0, 7, 42]).unwrap(),
+            ApplyExtrinsicResult::Invalid("The transaction was rejected by the runtime")
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_variants() {
+        assert!(decode(&[2]).is_err());
+        assert!(decode(&[0, 2]).is_err());
+        assert!(decode(&[1, 0]).is_err());
+        assert!(decode(&[]).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::services::keystore::{random_bytes, EncryptedSeed, KeystoreError};
+use gloo::timers::future::TimeoutFuture;
+use serde::{Deserialize, Serialize};
+
+/// Local storage key of the [`VoteVault`].
+pub const VOTE_VAULT_KEY: &str = "vote-vault";
+
+/// keccak-256 commitment checked by the chain at reveal time: the choice
+/// followed by the salt, as text.
+pub fn vote_commit(choice: &str, salt: &str) -> [u8; 32] {
+    sp_core_hashing::keccak_256(format!("{choice}{salt}").as_bytes())
+}
+
+/// 32 random bytes as hex. The pallet reads the salt back as UTF-8.
+pub fn generate_salt() -> Result<String, KeystoreError> {
+    Ok(hex::encode(random_bytes::<32>()?))
+}
+
+/// Gives the browser a turn to paint before sealing or opening a vote. The
+/// Argon2 key derivation blocks the UI thread while it runs, so the form
+/// shows that it is busy first.
+pub async fn before_key_derivation() {
+    TimeoutFuture::new(0).await;
+}
+
+/// What is needed to reveal a committed vote.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VoteSecret {
+    pub choice: String,
+    pub salt: String,
+    /// `0x` prefixed hex of the commit sent to the chain.
+    pub commit: String,
+    /// Latest finalized block when the vote was saved.
+    pub block: Option<u32>,
+}
+
+/// Vote of `account` in one game. Only the secret is encrypted, the rest is
+/// public on chain once the commit is in.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VaultEntry {
+    /// Pallet of the game, see `SchellingGameKind::PALLET`.
+    pub game: String,
+    pub game_id: String,
+    pub account: String,
+    pub saved_at_ms: u64,
+    pub secret: EncryptedSeed,
+}
+
+impl VaultEntry {
+    pub fn seal(
+        game: &str,
+        game_id: &str,
+        account: &str,
+        secret: &VoteSecret,
+        password: &str,
+    ) -> Result<Self, KeystoreError> {
+        let json =
+            serde_json::to_string(secret).map_err(|e| KeystoreError::Encryption(e.to_string()))?;
+        Ok(Self {
+            game: game.to_string(),
+            game_id: game_id.to_string(),
+            account: account.to_string(),
+            saved_at_ms: js_sys::Date::now() as u64,
+            secret: EncryptedSeed::encrypt(&json, password)?,
+        })
+    }
+
+    pub fn open(&self, password: &str) -> Result<VoteSecret, KeystoreError> {
+        let json = self.secret.decrypt(password)?;
+        serde_json::from_str(&json)
+            .map_err(|e| KeystoreError::Corrupted(format!("Invalid vote entry: {e}")))
+    }
+
+    fn is_vote(&self, game: &str, game_id: &str, account: &str) -> bool {
+        self.game == game && self.game_id == game_id && self.account == account
+    }
+}
+
+/// Saved commits, kept so a lost salt does not cost the juror's stake.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct VoteVault {
+    pub entries: Vec<VaultEntry>,
+}
+
+impl VoteVault {
+    pub fn parse(json: &str) -> Result<Self, KeystoreError> {
+        serde_json::from_str(json)
+            .map_err(|e| KeystoreError::Corrupted(format!("Not a vote vault: {e}")))
+    }
+
+    pub fn to_json(&self) -> Result<String, KeystoreError> {
+        serde_json::to_string_pretty(self).map_err(|e| KeystoreError::Encryption(e.to_string()))
+    }
+
+    /// Saved votes of `account` in one game, newest first. A game can hold
+    /// several when the vote was committed again, and only one of them
+    /// matches the commit on chain.
+    pub fn find(&self, game: &str, game_id: &str, account: &str) -> Vec<&VaultEntry> {
+        let mut votes: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|e| e.is_vote(game, game_id, account))
+            .collect();
+        votes.sort_by_key(|e| std::cmp::Reverse(e.saved_at_ms));
+        votes
+    }
+
+    /// Adds `entry`. Earlier votes of the same game are kept, since the
+    /// new commit may never reach the chain.
+    pub fn insert(&mut self, entry: VaultEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Appends the votes of `other` that are not in the vault yet and returns
+    /// how many were added.
+    pub fn merge(&mut self, other: VoteVault) -> usize {
+        let mut added = 0;
+        for entry in other.entries {
+            if !self.entries.contains(&entry) {
+                self.entries.push(entry);
+                added += 1;
+            }
+        }
+        added
+    }
+}